@@ -0,0 +1,130 @@
+/*!
+Aberration corrections accepted by the CSPICE geometry routines.
+*/
+
+use crate::Error;
+use std::ffi::CStr;
+use std::fmt;
+use std::str::FromStr;
+
+/**
+Aberration correction to apply when computing the apparent position of a target.
+
+The variants map one to one to the `abcorr` strings of CSPICE, see for instance the
+[C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkezr_c.html).
+The `X` variants are the transmission cases, the others the reception cases.
+
+For backward compatibility, every function taking an [`Aberration`] also accepts the usual
+strings such as `"NONE"` or `"LT+S"`. Parsing ignores case and blanks, just like CSPICE does. A
+misspelled correction passed as a string makes the function panic before reaching CSPICE, whereas
+[`str::parse`] returns [`Error::InvalidAberration`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Aberration {
+    /// No correction, geometric state.
+    #[default]
+    None,
+    /// Light time correction, reception case.
+    Lt,
+    /// Light time and stellar aberration corrections, reception case.
+    LtS,
+    /// Converged Newtonian light time correction, reception case.
+    Cn,
+    /// Converged Newtonian light time and stellar aberration corrections, reception case.
+    CnS,
+    /// Light time correction, transmission case.
+    XLt,
+    /// Light time and stellar aberration corrections, transmission case.
+    XLtS,
+    /// Converged Newtonian light time correction, transmission case.
+    XCn,
+    /// Converged Newtonian light time and stellar aberration corrections, transmission case.
+    XCnS,
+}
+
+impl Aberration {
+    /// All the aberration corrections.
+    pub const ALL: [Self; 9] = [
+        Self::None,
+        Self::Lt,
+        Self::LtS,
+        Self::Cn,
+        Self::CnS,
+        Self::XLt,
+        Self::XLtS,
+        Self::XCn,
+        Self::XCnS,
+    ];
+
+    /// String understood by CSPICE.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "NONE",
+            Self::Lt => "LT",
+            Self::LtS => "LT+S",
+            Self::Cn => "CN",
+            Self::CnS => "CN+S",
+            Self::XLt => "XLT",
+            Self::XLtS => "XLT+S",
+            Self::XCn => "XCN",
+            Self::XCnS => "XCN+S",
+        }
+    }
+
+    /// Null-terminated string understood by CSPICE, without allocation.
+    pub fn as_cstr(&self) -> &'static CStr {
+        let bytes: &'static [u8] = match self {
+            Self::None => b"NONE\0",
+            Self::Lt => b"LT\0",
+            Self::LtS => b"LT+S\0",
+            Self::Cn => b"CN\0",
+            Self::CnS => b"CN+S\0",
+            Self::XLt => b"XLT\0",
+            Self::XLtS => b"XLT+S\0",
+            Self::XCn => b"XCN\0",
+            Self::XCnS => b"XCN+S\0",
+        };
+        CStr::from_bytes_with_nul(bytes).unwrap()
+    }
+
+    /// Whether light time is corrected.
+    pub fn has_light_time(&self) -> bool {
+        *self != Self::None
+    }
+
+    /// Whether stellar aberration is corrected.
+    pub fn has_stellar(&self) -> bool {
+        matches!(self, Self::LtS | Self::CnS | Self::XLtS | Self::XCnS)
+    }
+
+    /// Whether this is a transmission case correction.
+    pub fn is_transmission(&self) -> bool {
+        matches!(self, Self::XLt | Self::XLtS | Self::XCn | Self::XCnS)
+    }
+}
+
+impl fmt::Display for Aberration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Aberration {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let squeezed = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_uppercase();
+
+        Self::ALL
+            .iter()
+            .find(|abcorr| abcorr.as_str() == squeezed)
+            .copied()
+            .ok_or_else(|| Error::InvalidAberration(s.to_string()))
+    }
+}
+
+impl_from_str!(Aberration);
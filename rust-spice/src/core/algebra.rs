@@ -0,0 +1,192 @@
+/*!
+Typed versions of the geometry functions, with [`nalgebra`] vectors and matrices.
+
+## Description
+
+The matrices of CSPICE are stored row by row: `m[i][j]` is the element at row `i` and column `j`,
+and this is the layout of the `[[f64; 3]; 3]` arrays of [`raw`]. The matrices of [`nalgebra`] are
+stored column by column, so that `Matrix3::from(m)` builds the transpose of `m`. The functions of
+this module do the conversion once and for all: use [`matrix3`] and [`rows3`] instead of
+transposing by hand.
+
+## Quaternions
+
+The quaternions of CSPICE, [`raw::m2q`] and [`raw::q2m`], are written scalar first
+`[cos(θ/2), sin(θ/2) a]` and stand for the rotation of vectors by the angle `θ` about the axis `a`,
+following the right-hand rule. This is the convention of [`UnitQuaternion`], so that [`quaternion`]
+keeps the components in order.
+
+The "engineering" quaternions, common in attitude control, are written scalar last and stand for
+the inverse rotation, that is the rotation of frames. Their matrix is the transpose of the one of
+the SPICE quaternion with the same components: use [`engineering_to_spice`] and
+[`spice_to_engineering`] to convert between both while keeping the same matrix.
+
+```ignore
+use spice::algebra;
+
+let rotation = algebra::pxform("J2000", "ECLIPJ2000", et);
+let (position, _) = algebra::spkpos("DIMORPHOS", et, "J2000", "NONE", "SUN");
+let ecliptic = rotation * position;
+```
+*/
+
+use crate::na::{Matrix3, Quaternion, Rotation3, Unit, UnitQuaternion, Vector3, Vector6};
+use crate::{raw, Aberration};
+
+/// Vector from a 3-array.
+pub fn vector3(v: [f64; 3]) -> Vector3<f64> {
+    Vector3::from(v)
+}
+
+/// Vector from a 6-array, such as a state.
+pub fn vector6(v: [f64; 6]) -> Vector6<f64> {
+    Vector6::from(v)
+}
+
+/// Matrix from the rows of a CSPICE matrix.
+pub fn matrix3(m: [[f64; 3]; 3]) -> Matrix3<f64> {
+    Matrix3::from_fn(|i, j| m[i][j])
+}
+
+/// Rows of a matrix, as expected by CSPICE.
+pub fn rows3(m: &Matrix3<f64>) -> [[f64; 3]; 3] {
+    let mut rows = [[0.0; 3]; 3];
+    for (i, row) in rows.iter_mut().enumerate() {
+        for (j, element) in row.iter_mut().enumerate() {
+            *element = m[(i, j)];
+        }
+    }
+    rows
+}
+
+/// Rotation from the rows of a CSPICE rotation matrix, which is not checked to be orthogonal.
+pub fn rotation3(m: [[f64; 3]; 3]) -> Rotation3<f64> {
+    Rotation3::from_matrix_unchecked(matrix3(m))
+}
+
+/// Unit quaternion from a SPICE quaternion.
+pub fn quaternion(q: [f64; 4]) -> UnitQuaternion<f64> {
+    UnitQuaternion::from_quaternion(Quaternion::new(q[0], q[1], q[2], q[3]))
+}
+
+/// SPICE quaternion from a unit quaternion, with a non-negative scalar part as [`raw::m2q`].
+pub fn spice_quaternion(q: &UnitQuaternion<f64>) -> [f64; 4] {
+    let sign = if q.w < 0.0 { -1.0 } else { 1.0 };
+    [sign * q.w, sign * q.i, sign * q.j, sign * q.k]
+}
+
+/// Engineering quaternion `[x, y, z, w]` of the same matrix as the SPICE quaternion `[w, x, y, z]`.
+pub fn spice_to_engineering(q: [f64; 4]) -> [f64; 4] {
+    [-q[1], -q[2], -q[3], q[0]]
+}
+
+/// SPICE quaternion `[w, x, y, z]` of the same matrix as the engineering quaternion `[x, y, z, w]`.
+pub fn engineering_to_spice(q: [f64; 4]) -> [f64; 4] {
+    [q[3], -q[0], -q[1], -q[2]]
+}
+
+/**
+Unit quaternion of a rotation matrix.
+
+See [`raw::m2q`].
+*/
+pub fn m2q(r: &Matrix3<f64>) -> UnitQuaternion<f64> {
+    quaternion(raw::m2q(rows3(r)))
+}
+
+/**
+Rotation matrix of a unit quaternion.
+
+See [`raw::q2m`].
+*/
+pub fn q2m(q: &UnitQuaternion<f64>) -> Matrix3<f64> {
+    matrix3(raw::q2m(spice_quaternion(q)))
+}
+
+/**
+Rotation of vectors by `angle` radians about `axis`.
+
+See [`raw::axisar`].
+*/
+pub fn axisar(axis: &Vector3<f64>, angle: f64) -> Rotation3<f64> {
+    rotation3(raw::axisar((*axis).into(), angle))
+}
+
+/**
+Unit axis and angle in [0, pi] of a rotation matrix.
+
+See [`raw::raxisa`].
+*/
+pub fn raxisa(r: &Matrix3<f64>) -> (Unit<Vector3<f64>>, f64) {
+    let (axis, angle) = raw::raxisa(rows3(r));
+    (Unit::new_normalize(vector3(axis)), angle)
+}
+
+/**
+Rotation transforming position vectors from one frame to another at an epoch.
+
+See [`raw::pxform`].
+*/
+pub fn pxform(from: &str, to: &str, et: f64) -> Rotation3<f64> {
+    rotation3(raw::pxform(from, to, et))
+}
+
+/**
+Rotation transforming position vectors from one frame at an epoch to another frame at another
+epoch.
+
+See [`raw::pxfrm2`].
+*/
+pub fn pxfrm2(from: &str, to: &str, etfrom: f64, etto: f64) -> Rotation3<f64> {
+    rotation3(raw::pxfrm2(from, to, etfrom, etto))
+}
+
+/**
+Product of a matrix and a vector.
+
+See [`raw::mxv`].
+*/
+pub fn mxv(m: &Matrix3<f64>, v: &Vector3<f64>) -> Vector3<f64> {
+    vector3(raw::mxv(rows3(m), (*v).into()))
+}
+
+/**
+Transpose of a matrix.
+
+See [`raw::xpose`].
+*/
+pub fn xpose(m: &Matrix3<f64>) -> Matrix3<f64> {
+    matrix3(raw::xpose(rows3(m)))
+}
+
+/**
+Position of a target relative to an observer, and the light time.
+
+See [`raw::spkpos`].
+*/
+pub fn spkpos(
+    targ: &str,
+    et: f64,
+    frame: &str,
+    abcorr: impl Into<Aberration>,
+    obs: &str,
+) -> (Vector3<f64>, f64) {
+    let (position, lt) = raw::spkpos(targ, et, frame, abcorr, obs);
+    (vector3(position), lt)
+}
+
+/**
+State of a target relative to an observer, and the light time.
+
+See [`raw::spkezr`].
+*/
+pub fn spkezr(
+    targ: &str,
+    et: f64,
+    frame: &str,
+    abcorr: impl Into<Aberration>,
+    obs: &str,
+) -> (Vector6<f64>, f64) {
+    let (state, lt) = raw::spkezr(targ, et, frame, abcorr, obs);
+    (vector6(state), lt)
+}
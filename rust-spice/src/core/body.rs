@@ -0,0 +1,119 @@
+/*!
+Bodies known to SPICE and their constants from the kernel pool.
+*/
+
+use crate::{neat, raw};
+use std::fmt;
+
+/**
+A body known to SPICE, identified by its NAIF ID.
+
+The name/ID translation is done with [`raw::bodn2c`] and [`neat::bodc2n`], and the constants are
+read from the kernel pool with [`raw::bodvcd`] once their presence and their size are checked with
+[`raw::dtpool`]. A body needs no name to be built from its ID or to read its constants. Angles are given in degrees and rates in degrees per day or century, as found in
+the PCK.
+
+```ignore
+let didymos = spice::Body::from_name("DIDYMOS").unwrap();
+let [a, b, c] = didymos.radii().unwrap();
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Body {
+    id: i32,
+}
+
+impl Body {
+    /// Body from its name, if it is known to SPICE.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match raw::bodn2c(name) {
+            (id, true) => Some(Self { id }),
+            _ => None,
+        }
+    }
+
+    /// Body from its NAIF ID, whether a name is associated with it or not.
+    pub fn from_id(id: i32) -> Self {
+        Self { id }
+    }
+
+    /// Define a new name/ID pair with [`raw::boddef`] and return the body.
+    pub fn define(name: &str, id: i32) -> Self {
+        raw::boddef(name, id);
+        Self { id }
+    }
+
+    /// NAIF ID of the body.
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    /// Name associated with the body.
+    pub fn name(&self) -> Option<String> {
+        match neat::bodc2n(self.id) {
+            (name, true) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Whether the kernel pool holds the item `BODY<ID>_<item>`.
+    pub fn has(&self, item: &str) -> bool {
+        raw::bodfnd(self.id, item)
+    }
+
+    /// All the values of the item `BODY<ID>_<item>` from the kernel pool, if it holds numbers.
+    pub fn values(&self, item: &str) -> Option<Vec<f64>> {
+        match raw::dtpool(&format!("BODY{}_{}", self.id, item)) {
+            (n, 'N', true) => Some(raw::bodvcd(self.id, item, n)),
+            _ => None,
+        }
+    }
+
+    /// Triaxial radii, in km.
+    pub fn radii(&self) -> Option<[f64; 3]> {
+        self.coefficients("RADII")
+    }
+
+    /// Gravitational parameter, in km³/s².
+    pub fn gm(&self) -> Option<f64> {
+        self.values("GM").map(|v| v[0])
+    }
+
+    /// Right ascension and declination polynomials of the north pole, in degrees and degrees per
+    /// century.
+    pub fn pole_ra_dec(&self) -> Option<([f64; 3], [f64; 3])> {
+        Some((
+            self.coefficients("POLE_RA")?,
+            self.coefficients("POLE_DEC")?,
+        ))
+    }
+
+    /// Prime meridian polynomial, in degrees and degrees per day.
+    pub fn pm(&self) -> Option<[f64; 3]> {
+        self.coefficients("PM")
+    }
+
+    /// First three coefficients of an item, missing trailing ones being zero as in CSPICE.
+    fn coefficients(&self, item: &str) -> Option<[f64; 3]> {
+        let values = self.values(item)?;
+        let n = values.len().min(3);
+        let mut coefficients = [0.0; 3];
+        coefficients[..n].copy_from_slice(&values[..n]);
+        Some(coefficients)
+    }
+}
+
+impl From<Body> for i32 {
+    fn from(body: Body) -> Self {
+        body.id
+    }
+}
+
+impl fmt::Display for Body {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "{}", self.id),
+        }
+    }
+}
@@ -0,0 +1,294 @@
+/*!
+Ray tracing of plate models in Rust, accelerated by a bounding volume hierarchy.
+*/
+
+use crate::math::{vcrss, vdist, vdot, vlcom, vsub, vzero, Vector};
+use crate::{Error, PlateModel};
+use std::thread;
+
+/// Largest number of plates in a leaf of the hierarchy.
+const LEAF_SIZE: usize = 4;
+
+/// Tolerance on the barycentric coordinates of the intercepts, so that rays hitting the edge
+/// shared by two plates do not pass between them.
+const EDGE_TOLERANCE: f64 = 1e-10;
+
+/**
+Intersection of a ray with a plate model.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    /// 1-based ID of the plate hit by the ray, as in [`crate::raw::dskx02`].
+    pub plate: i32,
+    /// Intercept.
+    pub point: Vector,
+    /// Distance between the vertex of the ray and the intercept.
+    pub distance: f64,
+}
+
+/// Node of the hierarchy, a leaf if it holds plates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Node {
+    min: Vector,
+    max: Vector,
+    /// Index of the first plate of a leaf, or of the first child of an inner node, whose second
+    /// child follows it.
+    first: usize,
+    /// Number of plates of a leaf, zero for an inner node.
+    count: usize,
+}
+
+/**
+Bounding volume hierarchy of the plates of a [`PlateModel`], intersecting rays with the model
+without CSPICE.
+
+[`raw::dskx02`][crate::raw::dskx02] traces one ray at a time through the global state of CSPICE.
+The hierarchy holds its own copy of the plates and is immutable once built, so that it can be
+shared between threads: [`Bvh::intersect`] can be called from any of them, and
+[`Bvh::intersect_batch`] spreads rays over the available cores.
+
+The hierarchy is split at the median of the centers of the plates along the longest axis of their
+bounds. A ray hits the nearest plate along its direction, starting at its vertex.
+
+```ignore
+let model = spice::PlateModel::from_dsk(handle, dladsc);
+let bvh = spice::Bvh::new(&model)?;
+let hits = bvh.intersect_batch(&vertices, &directions);
+```
+*/
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Bvh {
+    nodes: Vec<Node>,
+    /// Vertices of the plates, in the order of the leaves.
+    corners: Vec<[Vector; 3]>,
+    /// 1-based IDs of the plates, in the order of the leaves.
+    ids: Vec<i32>,
+}
+
+impl Bvh {
+    /// Hierarchy of the plates of a model, once checked with [`PlateModel::validate`].
+    pub fn new(model: &PlateModel) -> Result<Self, Error> {
+        model.validate()?;
+        let corners = model
+            .plates
+            .iter()
+            .map(|plate| plate.map(|i| model.vertices[i as usize - 1]))
+            .collect::<Vec<_>>();
+
+        let mut bvh = Self::default();
+        if corners.is_empty() {
+            return Ok(bvh);
+        }
+
+        // The plates are sorted through a permutation of their indices.
+        let centers = corners
+            .iter()
+            .map(|[a, b, c]| [0, 1, 2].map(|k| (a[k] + b[k] + c[k]) / 3.0))
+            .collect::<Vec<_>>();
+        let mut order = (0..corners.len()).collect::<Vec<_>>();
+        bvh.nodes.push(Node {
+            min: [0.0; 3],
+            max: [0.0; 3],
+            first: 0,
+            count: order.len(),
+        });
+        bvh.split(0, &mut order, &corners, &centers);
+
+        bvh.corners = order.iter().map(|&i| corners[i]).collect();
+        bvh.ids = order.iter().map(|&i| i as i32 + 1).collect();
+        Ok(bvh)
+    }
+
+    /// Number of plates in the hierarchy.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether the hierarchy holds no plate.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Bound a node, and split it in two children if it holds too many plates.
+    fn split(
+        &mut self,
+        index: usize,
+        order: &mut [usize],
+        corners: &[[Vector; 3]],
+        centers: &[Vector],
+    ) {
+        let Node { first, count, .. } = self.nodes[index];
+        let plates = &mut order[first..first + count];
+
+        let mut min = [f64::MAX; 3];
+        let mut max = [f64::MIN; 3];
+        for corner in plates.iter().flat_map(|&i| corners[i].iter()) {
+            for k in 0..3 {
+                min[k] = min[k].min(corner[k]);
+                max[k] = max[k].max(corner[k]);
+            }
+        }
+        self.nodes[index].min = min;
+        self.nodes[index].max = max;
+
+        if count <= LEAF_SIZE {
+            return;
+        }
+
+        let extent = vsub(&max, &min);
+        let axis = (0..3)
+            .max_by(|&a, &b| extent[a].total_cmp(&extent[b]))
+            .unwrap();
+        let half = count / 2;
+        plates.select_nth_unstable_by(half, |&a, &b| centers[a][axis].total_cmp(&centers[b][axis]));
+
+        let left = self.nodes.len();
+        for (first, count) in [(first, half), (first + half, count - half)] {
+            self.nodes.push(Node {
+                min: [0.0; 3],
+                max: [0.0; 3],
+                first,
+                count,
+            });
+        }
+        self.nodes[index].first = left;
+        self.nodes[index].count = 0;
+        self.split(left, order, corners, centers);
+        self.split(left + 1, order, corners, centers);
+    }
+
+    /// Nearest intersection of a ray with the plates, if any. The direction does not need to be a
+    /// unit vector, but must not be zero.
+    pub fn intersect(&self, vertex: &Vector, raydir: &Vector) -> Option<RayHit> {
+        if self.nodes.is_empty() || vzero(raydir) {
+            return None;
+        }
+
+        let inverse = raydir.map(|d| 1.0 / d);
+        let mut nearest: Option<(f64, usize)> = None;
+        let mut stack = vec![0];
+
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let limit = nearest.map_or(f64::INFINITY, |(t, _)| t);
+            if slab(node, vertex, &inverse).is_none_or(|t| t > limit) {
+                continue;
+            }
+
+            if node.count == 0 {
+                // The nearest child is visited first, so that it prunes the farthest one.
+                let (a, b) = (node.first, node.first + 1);
+                let ta = slab(&self.nodes[a], vertex, &inverse).unwrap_or(f64::INFINITY);
+                let tb = slab(&self.nodes[b], vertex, &inverse).unwrap_or(f64::INFINITY);
+                match ta <= tb {
+                    true => stack.extend([b, a]),
+                    false => stack.extend([a, b]),
+                }
+                continue;
+            }
+
+            for i in node.first..node.first + node.count {
+                if let Some(t) = triangle(&self.corners[i], vertex, raydir) {
+                    if nearest.is_none_or(|(best, _)| t < best) {
+                        nearest = Some((t, i));
+                    }
+                }
+            }
+        }
+
+        nearest.map(|(t, i)| {
+            let point = vlcom(1.0, vertex, t, raydir);
+            RayHit {
+                plate: self.ids[i],
+                point,
+                distance: vdist(vertex, &point),
+            }
+        })
+    }
+
+    /**
+    Nearest intersections of rays with the plates, traced in parallel over the available cores.
+
+    The rays are given by their vertices and their directions, which must have the same length.
+    */
+    pub fn intersect_batch(&self, vertices: &[Vector], raydirs: &[Vector]) -> Vec<Option<RayHit>> {
+        assert_eq!(
+            vertices.len(),
+            raydirs.len(),
+            "as many vertices as directions are expected"
+        );
+
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk = vertices.len().div_ceil(threads).max(1);
+        let mut hits = vec![None; vertices.len()];
+
+        thread::scope(|scope| {
+            for ((hits, vertices), raydirs) in hits
+                .chunks_mut(chunk)
+                .zip(vertices.chunks(chunk))
+                .zip(raydirs.chunks(chunk))
+            {
+                scope.spawn(move || {
+                    for ((hit, vertex), raydir) in hits.iter_mut().zip(vertices).zip(raydirs) {
+                        *hit = self.intersect(vertex, raydir);
+                    }
+                });
+            }
+        });
+
+        hits
+    }
+}
+
+/// Parameter along the ray where it enters the bounds of a node, if it does.
+fn slab(node: &Node, vertex: &Vector, inverse: &Vector) -> Option<f64> {
+    let mut near = 0.0_f64;
+    let mut far = f64::INFINITY;
+    for k in 0..3 {
+        // A ray parallel to the slab is either always in it or never.
+        if inverse[k].is_infinite() {
+            if vertex[k] < node.min[k] || vertex[k] > node.max[k] {
+                return None;
+            }
+            continue;
+        }
+        let t1 = (node.min[k] - vertex[k]) * inverse[k];
+        let t2 = (node.max[k] - vertex[k]) * inverse[k];
+        near = near.max(t1.min(t2));
+        far = far.min(t1.max(t2));
+    }
+    match near <= far {
+        true => Some(near),
+        false => None,
+    }
+}
+
+/// Parameter along the ray of its intersection with a plate, if any, with the algorithm of
+/// Möller and Trumbore.
+fn triangle([a, b, c]: &[Vector; 3], vertex: &Vector, raydir: &Vector) -> Option<f64> {
+    let ab = vsub(b, a);
+    let ac = vsub(c, a);
+    let p = vcrss(raydir, &ac);
+    let det = vdot(&ab, &p);
+    if det == 0.0 {
+        return None;
+    }
+
+    let inv = 1.0 / det;
+    let s = vsub(vertex, a);
+    let u = vdot(&s, &p) * inv;
+    if !(-EDGE_TOLERANCE..=1.0 + EDGE_TOLERANCE).contains(&u) {
+        return None;
+    }
+    let q = vcrss(&s, &ab);
+    let v = vdot(raydir, &q) * inv;
+    if v < -EDGE_TOLERANCE || u + v > 1.0 + EDGE_TOLERANCE {
+        return None;
+    }
+
+    let t = vdot(&ac, &q) * inv;
+    match t >= 0.0 {
+        true => Some(t),
+        false => None,
+    }
+}
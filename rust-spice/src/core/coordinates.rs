@@ -0,0 +1,223 @@
+/*!
+Coordinate systems as named fields, converted to and from rectangular vectors.
+
+Angles are in radians. The systems which need more than the vector to be defined, such as the
+reference ellipsoid of geodetic coordinates, are converted with methods taking these parameters
+instead of [`From`].
+*/
+
+use crate::raw;
+
+/**
+Latitudinal coordinates, see [`raw::latrec`] and [`raw::reclat`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Latitudinal {
+    /// Distance from the origin.
+    pub radius: f64,
+    /// Angle from the X axis in the XY plane, in [-pi, pi].
+    pub longitude: f64,
+    /// Angle from the XY plane, in [-pi/2, pi/2].
+    pub latitude: f64,
+}
+
+impl From<[f64; 3]> for Latitudinal {
+    fn from(rectan: [f64; 3]) -> Self {
+        let (radius, longitude, latitude) = raw::reclat(rectan);
+        Self {
+            radius,
+            longitude,
+            latitude,
+        }
+    }
+}
+
+impl From<Latitudinal> for [f64; 3] {
+    fn from(c: Latitudinal) -> Self {
+        raw::latrec(c.radius, c.longitude, c.latitude)
+    }
+}
+
+/**
+Spherical coordinates, see [`raw::sphrec`] and [`raw::recsph`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Spherical {
+    /// Distance from the origin.
+    pub radius: f64,
+    /// Angle from the Z axis, in [0, pi].
+    pub colatitude: f64,
+    /// Angle from the X axis in the XY plane, in [-pi, pi].
+    pub longitude: f64,
+}
+
+impl From<[f64; 3]> for Spherical {
+    fn from(rectan: [f64; 3]) -> Self {
+        let (radius, colatitude, longitude) = raw::recsph(rectan);
+        Self {
+            radius,
+            colatitude,
+            longitude,
+        }
+    }
+}
+
+impl From<Spherical> for [f64; 3] {
+    fn from(c: Spherical) -> Self {
+        raw::sphrec(c.radius, c.colatitude, c.longitude)
+    }
+}
+
+/**
+Cylindrical coordinates, see [`raw::cylrec`] and [`raw::reccyl`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Cylindrical {
+    /// Distance from the Z axis.
+    pub radius: f64,
+    /// Angle from the X axis in the XY plane, in [0, 2pi].
+    pub longitude: f64,
+    /// Height above the XY plane.
+    pub z: f64,
+}
+
+impl From<[f64; 3]> for Cylindrical {
+    fn from(rectan: [f64; 3]) -> Self {
+        let (radius, longitude, z) = raw::reccyl(rectan);
+        Self {
+            radius,
+            longitude,
+            z,
+        }
+    }
+}
+
+impl From<Cylindrical> for [f64; 3] {
+    fn from(c: Cylindrical) -> Self {
+        raw::cylrec(c.radius, c.longitude, c.z)
+    }
+}
+
+/**
+Range, right ascension and declination, see [`raw::radrec`] and [`raw::recrad`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RaDec {
+    /// Distance from the origin.
+    pub range: f64,
+    /// Right ascension, in [0, 2pi].
+    pub ra: f64,
+    /// Declination, in [-pi/2, pi/2].
+    pub dec: f64,
+}
+
+impl From<[f64; 3]> for RaDec {
+    fn from(rectan: [f64; 3]) -> Self {
+        let (range, ra, dec) = raw::recrad(rectan);
+        Self { range, ra, dec }
+    }
+}
+
+impl From<RaDec> for [f64; 3] {
+    fn from(c: RaDec) -> Self {
+        raw::radrec(c.range, c.ra, c.dec)
+    }
+}
+
+/**
+Geodetic coordinates relative to a reference spheroid of equatorial radius `re` and flattening
+`f`, see [`raw::georec`] and [`raw::recgeo`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Geodetic {
+    /// Angle from the X axis in the XY plane, in [-pi, pi].
+    pub longitude: f64,
+    /// Angle of the normal to the spheroid from the XY plane, in [-pi/2, pi/2].
+    pub latitude: f64,
+    /// Height above the spheroid along its normal.
+    pub altitude: f64,
+}
+
+impl Geodetic {
+    /// Geodetic coordinates of a rectangular vector.
+    pub fn from_rectangular(rectan: [f64; 3], re: f64, f: f64) -> Self {
+        let (longitude, latitude, altitude) = raw::recgeo(rectan, re, f);
+        Self {
+            longitude,
+            latitude,
+            altitude,
+        }
+    }
+
+    /// Rectangular vector of the geodetic coordinates.
+    pub fn to_rectangular(&self, re: f64, f: f64) -> [f64; 3] {
+        raw::georec(self.longitude, self.latitude, self.altitude, re, f)
+    }
+}
+
+/**
+Planetographic coordinates of a body relative to a reference spheroid of equatorial radius `re` and
+flattening `f`, see [`raw::pgrrec`] and [`raw::recpgr`].
+
+The sense of the longitude depends on the rotation of the body, read from the kernel pool.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Planetographic {
+    /// Planetographic longitude, in [0, 2pi].
+    pub longitude: f64,
+    /// Angle of the normal to the spheroid from the XY plane, in [-pi/2, pi/2].
+    pub latitude: f64,
+    /// Height above the spheroid along its normal.
+    pub altitude: f64,
+}
+
+impl Planetographic {
+    /// Planetographic coordinates of a rectangular vector.
+    pub fn from_rectangular(body: &str, rectan: [f64; 3], re: f64, f: f64) -> Self {
+        let [longitude, latitude, altitude] = raw::recpgr(body, rectan, re, f);
+        Self {
+            longitude,
+            latitude,
+            altitude,
+        }
+    }
+
+    /// Rectangular vector of the planetographic coordinates.
+    pub fn to_rectangular(&self, body: &str, re: f64, f: f64) -> [f64; 3] {
+        raw::pgrrec(body, self.longitude, self.latitude, self.altitude, re, f)
+    }
+}
+
+/**
+Range, azimuth and elevation, see [`raw::azlrec`] and [`raw::recazl`].
+
+`azccw` tells whether the azimuth increases counterclockwise about the positive Z axis, and
+`elplsz` whether the elevation increases towards it. Topocentric frames usually have a clockwise
+azimuth and an elevation positive towards the Z axis.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AzEl {
+    /// Distance from the origin.
+    pub range: f64,
+    /// Azimuth, in [0, 2pi].
+    pub azimuth: f64,
+    /// Elevation, in [-pi/2, pi/2].
+    pub elevation: f64,
+}
+
+impl AzEl {
+    /// Range, azimuth and elevation of a rectangular vector.
+    pub fn from_rectangular(rectan: [f64; 3], azccw: bool, elplsz: bool) -> Self {
+        let (range, azimuth, elevation) = raw::recazl(rectan, azccw, elplsz);
+        Self {
+            range,
+            azimuth,
+            elevation,
+        }
+    }
+
+    /// Rectangular vector of the range, azimuth and elevation.
+    pub fn to_rectangular(&self, azccw: bool, elplsz: bool) -> [f64; 3] {
+        raw::azlrec(self.range, self.azimuth, self.elevation, azccw, elplsz)
+    }
+}
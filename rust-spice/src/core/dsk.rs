@@ -0,0 +1,582 @@
+/*!
+Digital shape kernels: their coordinate systems, the traversal of their segments and the writing of
+type 2 segments.
+*/
+
+use crate::c::{
+    SPICE_DSK02_IXIFIX, SPICE_DSK02_KWCGPT, SPICE_DSK02_KWCGSC, SPICE_DSK02_KWDSC,
+    SPICE_DSK02_KWNP, SPICE_DSK02_KWNV, SPICE_DSK02_KWNVXT, SPICE_DSK02_KWPLAT, SPICE_DSK02_KWVERT,
+    SPICE_DSK02_KWVGRX, SPICE_DSK02_KWVTBD, SPICE_DSK02_KWVTLS, SPICE_DSK02_KWVTPL,
+    SPICE_DSK02_KWVTPT, SPICE_DSK02_KWVXLS, SPICE_DSK02_KWVXOR, SPICE_DSK02_KWVXPL,
+    SPICE_DSK02_KWVXPS, SPICE_DSK02_KWVXPT, SPICE_DSK02_KWVXSZ, SPICE_DSK02_MAXCEL,
+    SPICE_DSK02_MAXCGR, SPICE_DSK02_MAXVXP, SPICE_DSK02_MXNVLS, SPICE_DSK02_SPADSZ,
+    SPICE_DSK_CYLSYS, SPICE_DSK_GENCLS, SPICE_DSK_LATSYS, SPICE_DSK_NSYPAR, SPICE_DSK_PDTSYS,
+    SPICE_DSK_RECSYS, SPICE_DSK_SVFCLS,
+};
+use crate::core::{catch, validate_plates};
+use crate::{neat, raw, Error, DLADSC, DSKDSC};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// Voxel-plate associations per plate in the first workspaces of [`DskWriter`].
+const CELLS_PER_PLATE: usize = 8;
+
+/// Short messages of the errors signaled by `dskmi2_c` when a workspace is too small.
+const WORKSPACE_ERRORS: [&str; 5] = [
+    "SPICE(WORKSPACETOOSMALL)",
+    "SPICE(CELLARRAYTOOSMALL)",
+    "SPICE(PTRARRAYTOOSMALL)",
+    "SPICE(PLATELISTTOOSMALL)",
+    "SPICE(INTINDEXTOOSMALL)",
+];
+
+/**
+Coordinate system of the bounds of a DSK segment.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CoordinateSystem {
+    /// Planetocentric longitude, latitude and radius.
+    #[default]
+    Latitudinal,
+    /// Radius, longitude and height.
+    Cylindrical,
+    /// X, Y and Z.
+    Rectangular,
+    /// Planetodetic longitude, latitude and altitude above the spheroid of equatorial radius `re`
+    /// and flattening `f`.
+    Planetodetic { re: f64, f: f64 },
+}
+
+impl CoordinateSystem {
+    /// Code of the coordinate system, as found in the DSK descriptors.
+    pub fn code(&self) -> i32 {
+        (match self {
+            Self::Latitudinal => SPICE_DSK_LATSYS,
+            Self::Cylindrical => SPICE_DSK_CYLSYS,
+            Self::Rectangular => SPICE_DSK_RECSYS,
+            Self::Planetodetic { .. } => SPICE_DSK_PDTSYS,
+        }) as i32
+    }
+
+    /// Parameters of the coordinate system, as found in the DSK descriptors.
+    pub fn parameters(&self) -> [f64; SPICE_DSK_NSYPAR as usize] {
+        let mut corpar = [0.0; SPICE_DSK_NSYPAR as usize];
+        if let Self::Planetodetic { re, f } = self {
+            corpar[0] = *re;
+            corpar[1] = *f;
+        }
+        corpar
+    }
+
+    /// Coordinate system from its code and parameters, if the code is known.
+    pub fn from_code(code: i32, corpar: &[f64; SPICE_DSK_NSYPAR as usize]) -> Option<Self> {
+        match code as u32 {
+            SPICE_DSK_LATSYS => Some(Self::Latitudinal),
+            SPICE_DSK_CYLSYS => Some(Self::Cylindrical),
+            SPICE_DSK_RECSYS => Some(Self::Rectangular),
+            SPICE_DSK_PDTSYS => Some(Self::Planetodetic {
+                re: corpar[0],
+                f: corpar[1],
+            }),
+            _ => None,
+        }
+    }
+}
+
+/**
+Class of the data of a DSK segment.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DataClass {
+    /// Single-valued surface: any ray from the origin intersects it at most once.
+    Single,
+    /// General surface, which may be concave or have overhangs.
+    #[default]
+    General,
+}
+
+impl DataClass {
+    /// Code of the data class, as found in the DSK descriptors.
+    pub fn code(&self) -> i32 {
+        (match self {
+            Self::Single => SPICE_DSK_SVFCLS,
+            Self::General => SPICE_DSK_GENCLS,
+        }) as i32
+    }
+
+    /// Data class from its code, if it is known.
+    pub fn from_code(code: i32) -> Option<Self> {
+        match code as u32 {
+            SPICE_DSK_SVFCLS => Some(Self::Single),
+            SPICE_DSK_GENCLS => Some(Self::General),
+            _ => None,
+        }
+    }
+}
+
+/**
+Item of the data of a type 2 DSK segment, read with [`raw::dski02`] for the integer items and with
+[`raw::dskd02`] for the double precision ones.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dsk02Keyword {
+    /// Number of vertices, `SPICE_DSK02_KWNV`.
+    VertexCount,
+    /// Number of plates, `SPICE_DSK02_KWNP`.
+    PlateCount,
+    /// Number of voxels, `SPICE_DSK02_KWNVXT`.
+    VoxelCount,
+    /// Extent of the voxel grid along each axis, `SPICE_DSK02_KWVGRX`.
+    VoxelGridExtent,
+    /// Scale of the coarse voxels, in voxels, `SPICE_DSK02_KWCGSC`.
+    CoarseVoxelScale,
+    /// Size of the voxel-plate pointer array, `SPICE_DSK02_KWVXPS`.
+    VoxelPointerSize,
+    /// Size of the voxel-plate list, `SPICE_DSK02_KWVXLS`.
+    VoxelPlateListSize,
+    /// Size of the vertex-plate list, `SPICE_DSK02_KWVTLS`.
+    VertexPlateListSize,
+    /// Plates, as triples of vertex indices, `SPICE_DSK02_KWPLAT`.
+    Plates,
+    /// Voxel-plate pointer array, `SPICE_DSK02_KWVXPT`.
+    VoxelPointers,
+    /// Voxel-plate list, `SPICE_DSK02_KWVXPL`.
+    VoxelPlateList,
+    /// Vertex-plate pointer array, `SPICE_DSK02_KWVTPT`.
+    VertexPointers,
+    /// Vertex-plate list, `SPICE_DSK02_KWVTPL`.
+    VertexPlateList,
+    /// Coarse voxel grid pointers, `SPICE_DSK02_KWCGPT`.
+    CoarseGridPointers,
+    /// DSK descriptor, as double precision numbers, `SPICE_DSK02_KWDSC`.
+    Descriptor,
+    /// Bounds of the vertices, `SPICE_DSK02_KWVTBD`.
+    VertexBounds,
+    /// Origin of the voxel grid, `SPICE_DSK02_KWVXOR`.
+    VoxelOrigin,
+    /// Size of the voxels, in km, `SPICE_DSK02_KWVXSZ`.
+    VoxelSize,
+    /// Vertices, `SPICE_DSK02_KWVERT`.
+    Vertices,
+}
+
+impl Dsk02Keyword {
+    /// Every keyword, in the order of their codes.
+    pub const ALL: [Self; 19] = [
+        Self::VertexCount,
+        Self::PlateCount,
+        Self::VoxelCount,
+        Self::VoxelGridExtent,
+        Self::CoarseVoxelScale,
+        Self::VoxelPointerSize,
+        Self::VoxelPlateListSize,
+        Self::VertexPlateListSize,
+        Self::Plates,
+        Self::VoxelPointers,
+        Self::VoxelPlateList,
+        Self::VertexPointers,
+        Self::VertexPlateList,
+        Self::CoarseGridPointers,
+        Self::Descriptor,
+        Self::VertexBounds,
+        Self::VoxelOrigin,
+        Self::VoxelSize,
+        Self::Vertices,
+    ];
+
+    /// Code of the keyword, as expected by [`raw::dski02`] and [`raw::dskd02`].
+    pub fn code(&self) -> i32 {
+        (match self {
+            Self::VertexCount => SPICE_DSK02_KWNV,
+            Self::PlateCount => SPICE_DSK02_KWNP,
+            Self::VoxelCount => SPICE_DSK02_KWNVXT,
+            Self::VoxelGridExtent => SPICE_DSK02_KWVGRX,
+            Self::CoarseVoxelScale => SPICE_DSK02_KWCGSC,
+            Self::VoxelPointerSize => SPICE_DSK02_KWVXPS,
+            Self::VoxelPlateListSize => SPICE_DSK02_KWVXLS,
+            Self::VertexPlateListSize => SPICE_DSK02_KWVTLS,
+            Self::Plates => SPICE_DSK02_KWPLAT,
+            Self::VoxelPointers => SPICE_DSK02_KWVXPT,
+            Self::VoxelPlateList => SPICE_DSK02_KWVXPL,
+            Self::VertexPointers => SPICE_DSK02_KWVTPT,
+            Self::VertexPlateList => SPICE_DSK02_KWVTPL,
+            Self::CoarseGridPointers => SPICE_DSK02_KWCGPT,
+            Self::Descriptor => SPICE_DSK02_KWDSC,
+            Self::VertexBounds => SPICE_DSK02_KWVTBD,
+            Self::VoxelOrigin => SPICE_DSK02_KWVXOR,
+            Self::VoxelSize => SPICE_DSK02_KWVXSZ,
+            Self::Vertices => SPICE_DSK02_KWVERT,
+        }) as i32
+    }
+
+    /// Keyword from its code, if it is known.
+    pub fn from_code(code: i32) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|keyword| keyword.code() == code)
+    }
+
+    /// Whether the item is made of double precision numbers rather than integers.
+    pub fn is_double(&self) -> bool {
+        matches!(
+            self,
+            Self::Descriptor
+                | Self::VertexBounds
+                | Self::VoxelOrigin
+                | Self::VoxelSize
+                | Self::Vertices
+        )
+    }
+}
+
+/**
+Description of a DSK segment, decoded from its [`DSKDSC`].
+
+The codes of the data class and of the coordinate system are decoded as `None` when they are not
+known, and the frame name is blank when its code is not known.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct DskDescriptor {
+    /// Surface ID.
+    pub surface: i32,
+    /// ID of the body of the surface.
+    pub center: i32,
+    /// Data class.
+    pub data_class: Option<DataClass>,
+    /// Data type, 2 for plate models.
+    pub data_type: i32,
+    /// Name of the body-fixed frame of the segment.
+    pub frame: String,
+    /// Coordinate system of the bounds.
+    pub coordinate_system: Option<CoordinateSystem>,
+    /// Minimum and maximum of the three coordinates.
+    pub bounds: [[f64; 2]; 3],
+    /// Start and stop of the time span, in TDB seconds past J2000.
+    pub time_span: [f64; 2],
+}
+
+impl DskDescriptor {
+    /// Decode a DSK descriptor, resolving its frame name with [`neat::frmnam`].
+    pub fn new(dskdsc: &DSKDSC) -> Self {
+        Self {
+            surface: dskdsc.surfce,
+            center: dskdsc.center,
+            data_class: DataClass::from_code(dskdsc.dclass),
+            data_type: dskdsc.dtype,
+            frame: neat::frmnam(dskdsc.frmcde),
+            coordinate_system: CoordinateSystem::from_code(dskdsc.corsys, &dskdsc.corpar),
+            bounds: [
+                [dskdsc.co1min, dskdsc.co1max],
+                [dskdsc.co2min, dskdsc.co2max],
+                [dskdsc.co3min, dskdsc.co3max],
+            ],
+            time_span: [dskdsc.start, dskdsc.stop],
+        }
+    }
+}
+
+impl From<DSKDSC> for DskDescriptor {
+    fn from(dskdsc: DSKDSC) -> Self {
+        Self::new(&dskdsc)
+    }
+}
+
+/**
+Iterator over the segments of a DSK file, from the first one with [`raw::dlabfs`] and
+[`raw::dlafns`], or from the last one with [`raw::dlabbs`] and [`raw::dlafps`] when reversed.
+
+Each segment is yielded with its DLA descriptor, needed to read its data, and its decoded DSK
+descriptor. Both ends can be consumed alternately: the iteration stops when they meet. The file is
+opened and closed by the caller.
+
+```ignore
+let handle = spice::dasopr("dimorphos.bds");
+for (dladsc, descriptor) in spice::DskSegments::new(handle) {
+    println!("surface {} in {}", descriptor.surface, descriptor.frame);
+}
+spice::dascls(handle);
+```
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct DskSegments {
+    handle: i32,
+    front: Option<DLADSC>,
+    back: Option<DLADSC>,
+    done: bool,
+}
+
+impl DskSegments {
+    /// Iterator over the segments of the DSK file opened with the given handle.
+    pub fn new(handle: i32) -> Self {
+        Self {
+            handle,
+            front: None,
+            back: None,
+            done: false,
+        }
+    }
+
+    /// Yield a segment found from one end, unless it was already yielded from the other end.
+    fn step(&mut self, (dladsc, found): (DLADSC, bool), forward: bool) -> Option<DLADSC> {
+        let other = match forward {
+            true => self.back,
+            false => self.front,
+        };
+        if !found || other.is_some_and(|other| same_segment(&other, &dladsc)) {
+            self.done = true;
+            return None;
+        }
+        match forward {
+            true => self.front = Some(dladsc),
+            false => self.back = Some(dladsc),
+        }
+        Some(dladsc)
+    }
+}
+
+/// Whether two DLA descriptors describe the same segment.
+fn same_segment(a: &DLADSC, b: &DLADSC) -> bool {
+    (a.ibase, a.dbase, a.cbase) == (b.ibase, b.dbase, b.cbase)
+}
+
+impl Iterator for DskSegments {
+    type Item = (DLADSC, DskDescriptor);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = match self.front {
+            None => raw::dlabfs(self.handle),
+            Some(front) => raw::dlafns(self.handle, front),
+        };
+        let dladsc = self.step(next, true)?;
+        Some((dladsc, raw::dskgd(self.handle, dladsc).into()))
+    }
+}
+
+impl DoubleEndedIterator for DskSegments {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let previous = match self.back {
+            None => raw::dlabbs(self.handle),
+            Some(back) => raw::dlafps(self.handle, back),
+        };
+        let dladsc = self.step(previous, false)?;
+        Some((dladsc, raw::dskgd(self.handle, dladsc).into()))
+    }
+}
+
+/**
+Writer of type 2 DSK segments, the plate models.
+
+The writer holds the description of the segment: its surface, its body and its frame, and
+optionally its coordinate system, its data class, its time bounds and the scales of its voxels.
+[`DskWriter::write`] builds the spatial index with [`raw::dskmi2`], the bounds of the coordinates
+with [`raw::dskrb2`] and writes the segment with [`raw::dskw02`].
+
+The plates are the 1-based indices of their vertices, as in CSPICE. Unless set with
+[`DskWriter::coordinate_bounds`], the longitudes and latitudes of the segments cover the whole
+body, and the bounds of rectangular coordinates are the ones of the vertices. The bounds of the
+third coordinate are always computed from the plates. Type 2 segments do not support cylindrical
+coordinates.
+
+```ignore
+spice::DskWriter::new(1, -658031, "DIMORPHOS_FIXED")
+    .data_class(spice::DataClass::Single)
+    .write("dimorphos.bds", &vertices, &plates)?;
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct DskWriter {
+    surface: i32,
+    center: i32,
+    frame: String,
+    corsys: CoordinateSystem,
+    bounds: Option<[f64; 4]>,
+    dclass: DataClass,
+    first: f64,
+    last: f64,
+    finscl: f64,
+    corscl: i32,
+}
+
+impl DskWriter {
+    /// Time bounds of the segments by default: 50 Julian years on both sides of J2000.
+    pub const TIME_BOUND: f64 = 50.0 * 365.25 * 86400.0;
+
+    /// Writer of segments for a surface of a body, in a body-fixed frame.
+    pub fn new(surface: i32, center: i32, frame: &str) -> Self {
+        Self {
+            surface,
+            center,
+            frame: frame.to_string(),
+            corsys: CoordinateSystem::default(),
+            bounds: None,
+            dclass: DataClass::default(),
+            first: -Self::TIME_BOUND,
+            last: Self::TIME_BOUND,
+            finscl: 5.0,
+            corscl: 4,
+        }
+    }
+
+    /// Set the coordinate system, latitudinal by default.
+    pub fn coordinate_system(mut self, corsys: CoordinateSystem) -> Self {
+        self.corsys = corsys;
+        self
+    }
+
+    /**
+    Set the bounds of the first two coordinates of the segment: the longitudes and the latitudes
+    in radians, or the X and Y coordinates in km for rectangular coordinates.
+
+    The coverage of a regional model should be set, since the segments are selected by their
+    coverage.
+    */
+    pub fn coordinate_bounds(mut self, mncor1: f64, mxcor1: f64, mncor2: f64, mxcor2: f64) -> Self {
+        self.bounds = Some([mncor1, mxcor1, mncor2, mxcor2]);
+        self
+    }
+
+    /// Set the data class, general by default.
+    pub fn data_class(mut self, dclass: DataClass) -> Self {
+        self.dclass = dclass;
+        self
+    }
+
+    /// Set the time bounds of the segment, in TDB seconds past J2000.
+    pub fn time_bounds(mut self, first: f64, last: f64) -> Self {
+        self.first = first;
+        self.last = last;
+        self
+    }
+
+    /// Set the fine voxel scale and the coarse voxel scale of the spatial index, 5 and 4 by
+    /// default.
+    pub fn voxel_scales(mut self, finscl: f64, corscl: i32) -> Self {
+        self.finscl = finscl;
+        self.corscl = corscl;
+        self
+    }
+
+    /**
+    Write a new DSK file holding a segment of the plate model.
+
+    The plates are checked and their spatial index is built first, so that no file is created if
+    either fails.
+    */
+    pub fn write(
+        &self,
+        path: &str,
+        vertices: &[[f64; 3]],
+        plates: &[[i32; 3]],
+    ) -> Result<(), Error> {
+        validate_plates(vertices.len(), plates)?;
+        let (spaixd, spaixi) = self.spatial_index(vertices, plates)?;
+        let handle = catch(|| raw::dskopn(path, path, 0))?;
+        let written = catch(|| self.write_segment(handle, vertices, plates, &spaixd, &spaixi));
+        let closed = catch(|| raw::dskcls(handle, true));
+        written.and(closed)
+    }
+
+    /**
+    Spatial index of the plates.
+
+    The workspaces are sized from the number of plates rather than allocated at the largest sizes
+    accepted by CSPICE, which take hundreds of MB. A plate usually falls in a few fine voxels, so
+    that the workspaces start with room for [`CELLS_PER_PLATE`] voxel-plate associations per plate,
+    and are doubled while CSPICE finds them too small, up to these largest sizes. Any other error
+    is returned at once.
+    */
+    fn spatial_index(
+        &self,
+        vertices: &[[f64; 3]],
+        plates: &[[i32; 3]],
+    ) -> Result<([f64; SPICE_DSK02_SPADSZ as usize], Vec<i32>), Error> {
+        let corscl = self.corscl.max(1) as usize;
+        let mut cells = (CELLS_PER_PLATE * plates.len()).clamp(1, SPICE_DSK02_MAXCEL as usize);
+
+        loop {
+            // Every nonempty coarse voxel holds an association and points to all its fine voxels,
+            // and every nonempty fine voxel lists its count and its plates.
+            let coarse = cells.min(SPICE_DSK02_MAXCGR as usize);
+            let voxpsz = (coarse * corscl.pow(3)).min(SPICE_DSK02_MAXVXP as usize);
+            let voxlsz = (cells + voxpsz.min(cells)).min(SPICE_DSK02_MXNVLS as usize);
+            let spxisz = SPICE_DSK02_IXIFIX as usize + voxpsz + voxlsz;
+
+            let index = catch(|| {
+                raw::dskmi2(
+                    vertices,
+                    plates,
+                    self.finscl,
+                    self.corscl,
+                    cells,
+                    voxpsz as i32,
+                    voxlsz as i32,
+                    false,
+                    spxisz,
+                )
+            });
+            match index {
+                Err(Error::Spice { ref short, .. })
+                    if WORKSPACE_ERRORS.contains(&short.as_str())
+                        && cells < SPICE_DSK02_MAXCEL as usize =>
+                {
+                    cells = (2 * cells).min(SPICE_DSK02_MAXCEL as usize)
+                }
+                index => return index,
+            }
+        }
+    }
+
+    /// Write the segment into an open DSK file.
+    fn write_segment(
+        &self,
+        handle: i32,
+        vertices: &[[f64; 3]],
+        plates: &[[i32; 3]],
+        spaixd: &[f64],
+        spaixi: &[i32],
+    ) {
+        let corpar = self.corsys.parameters();
+        let (mncor3, mxcor3) = raw::dskrb2(vertices, plates, self.corsys.code(), corpar);
+        let [mncor1, mxcor1, mncor2, mxcor2] = match (self.bounds, self.corsys) {
+            (Some(bounds), _) => bounds,
+            (None, CoordinateSystem::Rectangular) => {
+                let bounds = |i: usize| {
+                    vertices.iter().fold([f64::MAX, f64::MIN], |[min, max], v| {
+                        [min.min(v[i]), max.max(v[i])]
+                    })
+                };
+                let [[mncor1, mxcor1], [mncor2, mxcor2]] = [bounds(0), bounds(1)];
+                [mncor1, mxcor1, mncor2, mxcor2]
+            }
+            (None, _) => [-PI, PI, -FRAC_PI_2, FRAC_PI_2],
+        };
+
+        raw::dskw02(
+            handle,
+            self.center,
+            self.surface,
+            self.dclass.code(),
+            &self.frame,
+            self.corsys.code(),
+            corpar,
+            mncor1,
+            mxcor1,
+            mncor2,
+            mxcor2,
+            mncor3,
+            mxcor3,
+            self.first,
+            self.last,
+            vertices,
+            plates,
+            spaixd,
+            spaixi,
+        );
+    }
+}
@@ -0,0 +1,41 @@
+/*!
+Ellipses of SPICE, see the [C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/ellipses.html).
+*/
+
+use crate::c::SpiceEllipse;
+
+/**
+Ellipse of the points `center + cos(θ) semi_major + sin(θ) semi_minor`.
+
+The semi-axes are orthogonal, and the semi-major axis is at least as long as the semi-minor one.
+Degenerate ellipses have a zero semi-minor axis, or both semi-axes zero.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Ellipse {
+    /// Center.
+    pub center: [f64; 3],
+    /// Semi-major axis.
+    pub semi_major: [f64; 3],
+    /// Semi-minor axis.
+    pub semi_minor: [f64; 3],
+}
+
+impl From<SpiceEllipse> for Ellipse {
+    fn from(ellipse: SpiceEllipse) -> Self {
+        Self {
+            center: ellipse.center,
+            semi_major: ellipse.semiMajor,
+            semi_minor: ellipse.semiMinor,
+        }
+    }
+}
+
+impl From<Ellipse> for SpiceEllipse {
+    fn from(ellipse: Ellipse) -> Self {
+        Self {
+            center: ellipse.center,
+            semiMajor: ellipse.semi_major,
+            semiMinor: ellipse.semi_minor,
+        }
+    }
+}
@@ -0,0 +1,309 @@
+/*!
+Epochs tied to a time scale, instead of bare floating numbers.
+*/
+
+use crate::{catch, neat, raw, Error, TimeFormat, UtcFormat, MAX_LEN_OUT, TIME_FORMAT};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Unix timestamp of J2000 in UTC, 2000-01-01T12:00:00.
+#[cfg(feature = "chrono")]
+const J2000_UNIX: i64 = 946_728_000;
+/// Julian date of J2000.
+const J2000_JD: f64 = 2_451_545.0;
+/// Offset between Julian and Modified Julian dates.
+const MJD_OFFSET: f64 = 2_400_000.5;
+/// Number of seconds in a day.
+const SPD: f64 = 86_400.0;
+
+/**
+An epoch, stored as TDB seconds past J2000, the ephemeris time (ET) of SPICE.
+
+The conversions to and from the other time scales go through [`raw::unitim`], [`raw::deltet`],
+[`raw::str2et`] and [`neat::et2utc`]. They need a leapseconds kernel to be loaded for UTC, TAI
+and GPS.
+
+The UTC seconds past J2000 of [`Epoch::utc_seconds`] are the ones of [`raw::deltet`]: they do not
+count leap seconds and are not continuous across them.
+
+```ignore
+let epoch: spice::Epoch = "2027-MAR-23 16:00:00".parse().unwrap();
+let later = epoch + std::time::Duration::from_secs(3600);
+assert_eq!(later.to_string(), "2027-MAR-23 17:00:00");
+```
+*/
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Epoch(f64);
+
+impl Epoch {
+    /// Epoch from TDB seconds past J2000.
+    pub fn from_et(et: f64) -> Self {
+        Self(et)
+    }
+
+    /// TDB seconds past J2000.
+    pub fn et(&self) -> f64 {
+        self.0
+    }
+
+    /// Epoch from a time string understood by [`raw::str2et`].
+    pub fn from_utc(s: &str) -> Result<Self, Error> {
+        catch(|| raw::str2et(s)).map(Self)
+    }
+
+    /// UTC string of the epoch, see [`neat::et2utc`] for `format` and `prec`.
+    pub fn to_utc(&self, format: impl Into<UtcFormat>, prec: i32) -> String {
+        neat::et2utc(self.0, format, prec)
+    }
+
+    /// String of the epoch following the picture built by `format`, see [`raw::timout`].
+    pub fn format(&self, format: &TimeFormat) -> String {
+        // The output of a picture can be longer than the picture itself, as for Julian dates.
+        raw::timout(self.0, &format.picture(), MAX_LEN_OUT)
+    }
+
+    /// Epoch from UTC seconds past J2000.
+    pub fn from_utc_seconds(utc: f64) -> Self {
+        Self(utc + raw::deltet(utc, "UTC"))
+    }
+
+    /// UTC seconds past J2000.
+    pub fn utc_seconds(&self) -> f64 {
+        self.0 - raw::deltet(self.0, "ET")
+    }
+
+    /// Epoch from TT seconds past J2000.
+    pub fn from_tt_seconds(tt: f64) -> Self {
+        Self(raw::unitim(tt, "TT", "TDB"))
+    }
+
+    /// TT seconds past J2000.
+    pub fn tt_seconds(&self) -> f64 {
+        raw::unitim(self.0, "TDB", "TT")
+    }
+
+    /// Epoch from TAI seconds past J2000.
+    pub fn from_tai_seconds(tai: f64) -> Self {
+        Self(raw::unitim(tai, "TAI", "TDB"))
+    }
+
+    /// TAI seconds past J2000.
+    pub fn tai_seconds(&self) -> f64 {
+        raw::unitim(self.0, "TDB", "TAI")
+    }
+
+    /// Epoch from GPS seconds past 1980 JAN 6 00:00:00 UTC.
+    pub fn from_gps_seconds(gps: f64) -> Self {
+        Self(raw::unitim(gps, "GPS", "TDB"))
+    }
+
+    /// GPS seconds past 1980 JAN 6 00:00:00 UTC.
+    pub fn gps_seconds(&self) -> f64 {
+        raw::unitim(self.0, "TDB", "GPS")
+    }
+
+    /// Epoch from a Julian date in TDB.
+    pub fn from_jd_tdb(jd: f64) -> Self {
+        Self(raw::unitim(jd, "JDTDB", "TDB"))
+    }
+
+    /// Julian date in TDB.
+    pub fn jd_tdb(&self) -> f64 {
+        raw::unitim(self.0, "TDB", "JDTDB")
+    }
+
+    /// Epoch from a Julian date in TT.
+    pub fn from_jd_tt(jd: f64) -> Self {
+        Self(raw::unitim(jd, "JDTDT", "TDB"))
+    }
+
+    /// Julian date in TT.
+    pub fn jd_tt(&self) -> f64 {
+        raw::unitim(self.0, "TDB", "JDTDT")
+    }
+
+    /// Epoch from a Julian date in UTC.
+    pub fn from_jd_utc(jd: f64) -> Self {
+        Self::from_utc_seconds((jd - J2000_JD) * SPD)
+    }
+
+    /// Julian date in UTC.
+    pub fn jd_utc(&self) -> f64 {
+        J2000_JD + self.utc_seconds() / SPD
+    }
+
+    /// Epoch from a Modified Julian date in TDB.
+    pub fn from_mjd_tdb(mjd: f64) -> Self {
+        Self::from_jd_tdb(mjd + MJD_OFFSET)
+    }
+
+    /// Modified Julian date in TDB.
+    pub fn mjd_tdb(&self) -> f64 {
+        self.jd_tdb() - MJD_OFFSET
+    }
+
+    /// Epoch from a Modified Julian date in TT.
+    pub fn from_mjd_tt(mjd: f64) -> Self {
+        Self::from_jd_tt(mjd + MJD_OFFSET)
+    }
+
+    /// Modified Julian date in TT.
+    pub fn mjd_tt(&self) -> f64 {
+        self.jd_tt() - MJD_OFFSET
+    }
+
+    /// Epoch from a Modified Julian date in UTC.
+    pub fn from_mjd_utc(mjd: f64) -> Self {
+        Self::from_jd_utc(mjd + MJD_OFFSET)
+    }
+
+    /// Modified Julian date in UTC.
+    pub fn mjd_utc(&self) -> f64 {
+        self.jd_utc() - MJD_OFFSET
+    }
+}
+
+impl From<Epoch> for f64 {
+    fn from(epoch: Epoch) -> Self {
+        epoch.0
+    }
+}
+
+impl fmt::Display for Epoch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&neat::timout(self.0, TIME_FORMAT))
+    }
+}
+
+impl FromStr for Epoch {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_utc(s)
+    }
+}
+
+impl PartialEq for Epoch {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Epoch {}
+
+impl PartialOrd for Epoch {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Epoch {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Add<Duration> for Epoch {
+    type Output = Self;
+
+    fn add(self, duration: Duration) -> Self::Output {
+        Self(self.0 + duration.as_secs_f64())
+    }
+}
+
+impl AddAssign<Duration> for Epoch {
+    fn add_assign(&mut self, duration: Duration) {
+        self.0 += duration.as_secs_f64();
+    }
+}
+
+impl Sub<Duration> for Epoch {
+    type Output = Self;
+
+    fn sub(self, duration: Duration) -> Self::Output {
+        Self(self.0 - duration.as_secs_f64())
+    }
+}
+
+impl SubAssign<Duration> for Epoch {
+    fn sub_assign(&mut self, duration: Duration) {
+        self.0 -= duration.as_secs_f64();
+    }
+}
+
+/// Signed number of TDB seconds between two epochs.
+impl Sub for Epoch {
+    type Output = f64;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.0 - other.0
+    }
+}
+
+/**
+Conversion of an UTC date from [`chrono`], which may fall in a leap second.
+*/
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl From<chrono::DateTime<chrono::Utc>> for Epoch {
+    fn from(datetime: chrono::DateTime<chrono::Utc>) -> Self {
+        let start = Self::from_utc_seconds((datetime.timestamp() - J2000_UNIX) as f64);
+        // Nanoseconds exceed one second during a leap second, and ET keeps flowing.
+        Self(start.0 + datetime.timestamp_subsec_nanos() as f64 * 1e-9)
+    }
+}
+
+/**
+Conversion to an UTC date of [`chrono`], which may fall in a leap second.
+*/
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl From<Epoch> for chrono::DateTime<chrono::Utc> {
+    fn from(epoch: Epoch) -> Self {
+        let start = |utc: i64| Epoch::from_utc_seconds(utc as f64).0;
+
+        // UTC second holding the epoch, lasting two seconds of ET if it ends with a leap second.
+        let mut utc = epoch.utc_seconds().floor() as i64;
+        while start(utc) > epoch.0 {
+            utc -= 1;
+        }
+        while start(utc + 1) <= epoch.0 {
+            utc += 1;
+        }
+        let leap = start(utc + 1) - start(utc) > 1.5;
+
+        let mut nanos = ((epoch.0 - start(utc)) * 1e9).floor() as u32;
+        if !leap {
+            nanos = nanos.min(999_999_999);
+        }
+
+        chrono::DateTime::from_timestamp(utc + J2000_UNIX, nanos).unwrap()
+    }
+}
+
+/**
+Conversion from an epoch of [`hifitime`], through TT which is free of leap seconds.
+*/
+#[cfg(feature = "hifitime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hifitime")))]
+impl From<hifitime::Epoch> for Epoch {
+    fn from(epoch: hifitime::Epoch) -> Self {
+        let j2000 = hifitime::Unit::Second * hifitime::ET_EPOCH_S;
+        Self::from_tt_seconds((epoch.to_tt_duration() - j2000).to_seconds())
+    }
+}
+
+/**
+Conversion to an epoch of [`hifitime`], through TT which is free of leap seconds.
+*/
+#[cfg(feature = "hifitime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hifitime")))]
+impl From<Epoch> for hifitime::Epoch {
+    fn from(epoch: Epoch) -> Self {
+        let j2000 = hifitime::Unit::Second * hifitime::ET_EPOCH_S;
+        Self::from_tt_duration(j2000 + hifitime::Unit::Second * epoch.tt_seconds())
+    }
+}
@@ -0,0 +1,129 @@
+/*!
+Errors raised by the idiomatic interface, either on the Rust side before anything is sent to
+CSPICE, or signaled by CSPICE itself.
+*/
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+/**
+Error type of the idiomatic interface.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The string does not name an aberration correction known to SPICE.
+    #[error("invalid aberration correction: `{0}`")]
+    InvalidAberration(String),
+    /// The string does not name an output format of `et2utc_c`.
+    #[error("invalid UTC format: `{0}`")]
+    InvalidUtcFormat(String),
+    /// The string is not a `METHOD` understood by the geometry functions, or combines a kind of
+    /// sub-point with a shape that does not support it.
+    #[error("invalid computation method: `{0}`")]
+    InvalidMethod(String),
+    /// The sample time string could not be turned into a format picture by `tpictr_c`.
+    #[error("invalid time picture sample: {0}")]
+    InvalidTimeSample(String),
+    /// The text kernel could not be read or lacks some variables.
+    #[error("invalid kernel: {0}")]
+    InvalidKernel(String),
+    /// The time string is not in a format understood by the Rust parser.
+    #[error("invalid time string: `{0}`")]
+    InvalidTime(String),
+    /// The mesh file could not be read or is malformed.
+    #[error("invalid mesh: {0}")]
+    InvalidMesh(String),
+    /// The item of a DSK segment does not hold values of the requested type.
+    #[error("invalid item: {0}")]
+    InvalidItem(String),
+    /// The name is not associated with an ID code.
+    #[error("unknown name: `{0}`")]
+    UnknownName(String),
+    /// A file could not be written.
+    #[error("input/output error: {0}")]
+    Io(String),
+    /// CSPICE signaled an error, with its short and long messages.
+    #[error("{short}: {long}")]
+    Spice { short: String, long: String },
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e.to_string())
+    }
+}
+
+/**
+Pointer to a static null-terminated string, to be sent as an input string to CSPICE.
+*/
+fn static_cstr(bytes: &'static [u8]) -> *mut c_char {
+    CStr::from_bytes_with_nul(bytes).unwrap().as_ptr() as *mut _
+}
+
+/**
+Get the short or long message of the last error signaled by CSPICE.
+*/
+fn get_message(option: &'static [u8]) -> String {
+    let mut message = [0 as c_char; 1841];
+    unsafe {
+        crate::c::getmsg_c(
+            static_cstr(option),
+            message.len() as _,
+            message.as_mut_ptr(),
+        );
+        CStr::from_ptr(message.as_ptr())
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/**
+Call `f` with the CSPICE error action set to `RETURN` and the error output silenced, and turn an
+error signaled meanwhile into [`Error::Spice`] instead of aborting the program.
+
+The error subsystem is reset and its previous settings are restored before returning.
+*/
+pub(crate) fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Error> {
+    let mut action = [0 as c_char; 32];
+    let mut report = [0 as c_char; 128];
+
+    unsafe {
+        crate::c::erract_c(
+            static_cstr(b"GET\0"),
+            action.len() as _,
+            action.as_mut_ptr(),
+        );
+        crate::c::errprt_c(
+            static_cstr(b"GET\0"),
+            report.len() as _,
+            report.as_mut_ptr(),
+        );
+        crate::c::erract_c(static_cstr(b"SET\0"), 0, static_cstr(b"RETURN\0"));
+        crate::c::errprt_c(static_cstr(b"SET\0"), 0, static_cstr(b"NONE\0"));
+    }
+
+    // Setting the output list only enables the given types, so start from none.
+    let report = unsafe { CStr::from_ptr(report.as_ptr()) }.to_string_lossy();
+    let report = CString::new(format!("NONE, {}", report)).unwrap();
+
+    let value = f();
+
+    let result = match unsafe { crate::c::failed_c() } != 0 {
+        true => {
+            let error = Error::Spice {
+                short: get_message(b"SHORT\0"),
+                long: get_message(b"LONG\0"),
+            };
+            unsafe { crate::c::reset_c() };
+            Err(error)
+        }
+        false => Ok(value),
+    };
+
+    unsafe {
+        crate::c::errprt_c(static_cstr(b"SET\0"), 0, report.as_ptr() as *mut _);
+        crate::c::erract_c(static_cstr(b"SET\0"), 0, action.as_mut_ptr());
+    }
+
+    result
+}
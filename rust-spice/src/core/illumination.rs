@@ -0,0 +1,80 @@
+/*!
+Illumination angles at surface points, and the sub-observer and sub-solar points.
+*/
+
+/**
+Illumination of a surface point, as found by [`raw::illumf`][crate::raw::illumf], on which
+[`neat::ilumin`][crate::neat::ilumin] and [`neat::illumg`][crate::neat::illumg] rely.
+
+The tuples of [`raw::illumf`][crate::raw::illumf] convert into it with [`From`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Illumination {
+    /// Epoch of the surface point, in TDB seconds past J2000.
+    pub trgepc: f64,
+    /// Vector from the observer to the surface point, in the body-fixed frame.
+    pub srfvec: [f64; 3],
+    /// Phase angle, in radians.
+    pub phase: f64,
+    /// Incidence angle, in radians.
+    pub incidence: f64,
+    /// Emission angle, in radians.
+    pub emission: f64,
+    /// Whether the point is visible from the observer: no part of the target surface lies
+    /// between them, which goes beyond the emission angle on DSK shapes.
+    pub visible: bool,
+    /// Whether the point is lit: no part of the target surface lies between the point and the
+    /// center of the source, which goes beyond the incidence angle on DSK shapes.
+    pub lit: bool,
+}
+
+impl From<(f64, [f64; 3], f64, f64, f64, bool, bool)> for Illumination {
+    fn from(
+        (trgepc, srfvec, phase, incidence, emission, visible, lit): (
+            f64,
+            [f64; 3],
+            f64,
+            f64,
+            f64,
+            bool,
+            bool,
+        ),
+    ) -> Self {
+        Self {
+            trgepc,
+            srfvec,
+            phase,
+            incidence,
+            emission,
+            visible,
+            lit,
+        }
+    }
+}
+
+/**
+Sub-observer or sub-solar point, as found by [`neat::subslr`][crate::neat::subslr] or
+[`raw::subpnt`][crate::raw::subpnt].
+
+The tuples of [`raw::subpnt`][crate::raw::subpnt] and [`raw::subslr`][crate::raw::subslr] convert
+into it with [`From`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SubPoint {
+    /// Sub-point, in the body-fixed frame.
+    pub spoint: [f64; 3],
+    /// Epoch of the sub-point, in TDB seconds past J2000.
+    pub trgepc: f64,
+    /// Vector from the observer to the sub-point, in the body-fixed frame.
+    pub srfvec: [f64; 3],
+}
+
+impl From<([f64; 3], f64, [f64; 3])> for SubPoint {
+    fn from((spoint, trgepc, srfvec): ([f64; 3], f64, [f64; 3])) -> Self {
+        Self {
+            spoint,
+            trgepc,
+            srfvec,
+        }
+    }
+}
@@ -0,0 +1,449 @@
+/*!
+Leapseconds kernels read in Rust, to convert between UTC and ET without CSPICE.
+*/
+
+use crate::Error;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Julian day number of 2000 JAN 01, whose noon is J2000.
+const J2000_JDN: i64 = 2_451_545;
+/// First day of the Gregorian calendar, 1582 OCT 15.
+const GREGORIAN_START: (i64, i64, i64) = (1582, 10, 15);
+/// Number of seconds in a day.
+const SPD: i64 = 86_400;
+/// Full names of the months, abbreviations being accepted from three letters.
+const MONTHS: [&str; 12] = [
+    "JANUARY",
+    "FEBRUARY",
+    "MARCH",
+    "APRIL",
+    "MAY",
+    "JUNE",
+    "JULY",
+    "AUGUST",
+    "SEPTEMBER",
+    "OCTOBER",
+    "NOVEMBER",
+    "DECEMBER",
+];
+
+/**
+Content of a leapseconds kernel (LSK), converting between UTC and ET as [`crate::raw::deltet`] and
+[`crate::raw::str2et`] do.
+
+Nothing is loaded in the kernel pool: a `LeapSeconds` is plain data which can be shared between
+threads and used without the [`crate::SpiceLock`].
+
+As in CSPICE, UTC seconds past J2000 are formal seconds, computed as if every day lasted 86400
+seconds. Calendar dates before 1582 OCT 15 are in the Julian calendar.
+
+```ignore
+let lsk = spice::LeapSeconds::from_file("naif0012.tls").unwrap();
+let et = lsk.str2et("2027-03-23T16:00:00").unwrap();
+let delta = lsk.delta_from_et(et);
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeapSeconds {
+    /// `DELTET/DELTA_T_A`, TDT - TAI in seconds.
+    delta_t_a: f64,
+    /// `DELTET/K`, amplitude of the periodic term in seconds.
+    k: f64,
+    /// `DELTET/EB`, eccentricity of the Earth-Moon barycenter orbit.
+    eb: f64,
+    /// `DELTET/M`, mean anomaly of the Earth-Moon barycenter at J2000 and its rate.
+    m: [f64; 2],
+    /// `DELTET/DELTA_AT`, pairs of TAI - UTC and UTC seconds from when it applies.
+    delta_at: Vec<(f64, f64)>,
+}
+
+impl LeapSeconds {
+    /// Read a leapseconds kernel.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        fs::read_to_string(path)
+            .map_err(|e| Error::InvalidKernel(format!("{}: {}", path.display(), e)))?
+            .parse()
+    }
+
+    /// Pairs of TAI - UTC and UTC seconds past J2000 from when it applies.
+    pub fn delta_at(&self) -> &[(f64, f64)] {
+        &self.delta_at
+    }
+
+    /// ET - UTC at an UTC epoch, in seconds, as `deltet_c` with `"UTC"`.
+    pub fn delta_from_utc(&self, utc: f64) -> f64 {
+        let leaps = self
+            .delta_at
+            .iter()
+            .rev()
+            .find(|(_, epoch)| utc >= *epoch)
+            .map_or(self.delta_at[0].0 - 1.0, |(delta, _)| *delta);
+
+        self.delta(utc + self.delta_t_a + leaps, leaps)
+    }
+
+    /// ET - UTC at an ET epoch, in seconds, as `deltet_c` with `"ET"`.
+    pub fn delta_from_et(&self, et: f64) -> f64 {
+        let leaps = self
+            .delta_at
+            .iter()
+            .rev()
+            .find(|(delta, epoch)| et >= epoch + self.delta_t_a + delta)
+            .map_or(self.delta_at[0].0 - 1.0, |(delta, _)| *delta);
+
+        self.delta(et, leaps)
+    }
+
+    /// ET - UTC from the TDT epoch `aet` and the number of leap seconds.
+    fn delta(&self, aet: f64, leaps: f64) -> f64 {
+        let m = self.m[0] + self.m[1] * aet;
+        let ea = m + self.eb * m.sin();
+        self.delta_t_a + leaps + self.k * ea.sin()
+    }
+
+    /// ET of UTC seconds past J2000.
+    pub fn utc_to_et(&self, utc: f64) -> f64 {
+        utc + self.delta_from_utc(utc)
+    }
+
+    /// UTC seconds past J2000 of an ET.
+    pub fn et_to_utc(&self, et: f64) -> f64 {
+        et - self.delta_from_et(et)
+    }
+
+    /**
+    ET of an UTC date, as [`crate::raw::str2et`] for the formats:
+
+    + `1972-01-01T00:00:00.000`, ISO calendar,
+    + `1972-001T00:00:00.000`, ISO day of year,
+    + `1972-JAN-01 00:00:00.000` or `1972 January 1 00:00:00`, calendar with month names.
+
+    The time of day may be truncated after any field, or omitted. A leap second is written as the
+    second `60` of the last minute of the day.
+    */
+    pub fn str2et(&self, s: &str) -> Result<f64, Error> {
+        let (utc, extra) = parse_utc(s)?;
+        Ok(self.utc_to_et(utc) + extra)
+    }
+}
+
+impl FromStr for LeapSeconds {
+    type Err = Error;
+
+    /// Parse the content of a leapseconds kernel.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pool = parse_kernel(s)?;
+
+        let get = |name: &str, n: usize| match pool.get(name) {
+            Some(values) if values.len() == n => Ok(values.clone()),
+            Some(_) => Err(Error::InvalidKernel(format!(
+                "`{}` should hold {} values",
+                name, n
+            ))),
+            None => Err(Error::InvalidKernel(format!("`{}` is missing", name))),
+        };
+
+        let delta_at = match pool.get("DELTET/DELTA_AT") {
+            Some(values) if !values.is_empty() && values.len() % 2 == 0 => {
+                values.chunks(2).map(|pair| (pair[0], pair[1])).collect()
+            }
+            Some(_) => {
+                return Err(Error::InvalidKernel(
+                    "`DELTET/DELTA_AT` should hold pairs of values".to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::InvalidKernel(
+                    "`DELTET/DELTA_AT` is missing".to_string(),
+                ))
+            }
+        };
+
+        let m = get("DELTET/M", 2)?;
+
+        Ok(Self {
+            delta_t_a: get("DELTET/DELTA_T_A", 1)?[0],
+            k: get("DELTET/K", 1)?[0],
+            eb: get("DELTET/EB", 1)?[0],
+            m: [m[0], m[1]],
+            delta_at,
+        })
+    }
+}
+
+/**
+Numeric variables assigned in the data blocks of a text kernel. Dates preceded by `@` are turned
+into UTC seconds past J2000, and string values are skipped.
+*/
+fn parse_kernel(text: &str) -> Result<HashMap<String, Vec<f64>>, Error> {
+    let mut data = String::new();
+    let mut in_data = false;
+    for line in text.lines() {
+        match line.trim() {
+            "\\begindata" => in_data = true,
+            "\\begintext" => in_data = false,
+            _ if in_data => {
+                data.push_str(line);
+                data.push('\n');
+            }
+            _ => {}
+        }
+    }
+
+    let mut pool: HashMap<String, Vec<f64>> = HashMap::new();
+    let mut tokens = tokenize(&data)?.into_iter();
+
+    while let Some(name) = tokens.next() {
+        let append = match tokens.next().as_deref() {
+            Some("=") => false,
+            Some("+=") => true,
+            _ => {
+                return Err(Error::InvalidKernel(format!(
+                    "expected an assignment after `{}`",
+                    name
+                )))
+            }
+        };
+
+        let mut values = Vec::new();
+        match tokens.next() {
+            Some(token) if token == "(" => loop {
+                match tokens.next() {
+                    Some(token) if token == ")" => break,
+                    Some(token) => values.extend(parse_value(&token)?),
+                    None => {
+                        return Err(Error::InvalidKernel(format!(
+                            "unclosed values of `{}`",
+                            name
+                        )))
+                    }
+                }
+            },
+            Some(token) => values.extend(parse_value(&token)?),
+            None => return Err(Error::InvalidKernel(format!("missing value of `{}`", name))),
+        }
+
+        let entry = pool.entry(name).or_default();
+        if !append {
+            entry.clear();
+        }
+        entry.extend(values);
+    }
+
+    Ok(pool)
+}
+
+/**
+Split the data of a text kernel into names, assignments, parentheses and values. Commas separate
+values as blanks do.
+*/
+fn tokenize(data: &str) -> Result<Vec<String>, Error> {
+    let mut tokens = Vec::new();
+    let mut chars = data.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() || c == ',' => {
+                chars.next();
+            }
+            '(' | ')' | '=' => {
+                tokens.push(c.to_string());
+                chars.next();
+            }
+            '+' if chars.clone().nth(1) == Some('=') => {
+                chars.nth(1);
+                tokens.push("+=".to_string());
+            }
+            '\'' => {
+                let mut token = String::from("'");
+                chars.next();
+                loop {
+                    match chars.next() {
+                        Some('\'') if chars.peek() == Some(&'\'') => {
+                            chars.next();
+                            token.push('\'');
+                        }
+                        Some('\'') => break,
+                        Some(c) => token.push(c),
+                        None => return Err(Error::InvalidKernel("unclosed string".to_string())),
+                    }
+                }
+                tokens.push(token);
+            }
+            _ => {
+                let mut token = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || matches!(c, ',' | '(' | ')' | '=') {
+                        break;
+                    }
+                    token.push(c);
+                    chars.next();
+                }
+                tokens.push(token);
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Value of a token, `None` for strings.
+fn parse_value(token: &str) -> Result<Option<f64>, Error> {
+    if token.starts_with('\'') {
+        return Ok(None);
+    }
+    if let Some(date) = token.strip_prefix('@') {
+        let (utc, extra) = parse_utc(date)?;
+        return Ok(Some(utc + extra));
+    }
+    token
+        .replace(['D', 'd'], "E")
+        .parse()
+        .map(Some)
+        .map_err(|_| Error::InvalidKernel(format!("invalid value `{}`", token)))
+}
+
+/**
+Formal UTC seconds past J2000 of a date, and the seconds in excess of the formal ones during a
+leap second.
+*/
+fn parse_utc(s: &str) -> Result<(f64, f64), Error> {
+    let invalid = || Error::InvalidTime(s.to_string());
+
+    // The `T` of ISO formats sits between digits, unlike the ones of month names.
+    let chars: Vec<char> = s.trim().chars().collect();
+    let normalized: String = chars
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| {
+            let digits = i > 0
+                && i + 1 < chars.len()
+                && chars[i - 1].is_ascii_digit()
+                && chars[i + 1].is_ascii_digit();
+            match (c, digits) {
+                ('T', true) | ('t', true) => ' ',
+                _ => c,
+            }
+        })
+        .collect();
+
+    let mut fields = normalized
+        .split(|c: char| c.is_whitespace() || matches!(c, '-' | '/' | ','))
+        .filter(|f| !f.is_empty());
+
+    let year: i64 = fields
+        .next()
+        .ok_or_else(invalid)?
+        .parse()
+        .map_err(|_| invalid())?;
+    let second = fields.next().ok_or_else(invalid)?;
+
+    let first_day = |month: i64, day: i64| julian_day_number(year, month, day);
+    let jdn = match MONTHS
+        .iter()
+        .position(|m| second.len() >= 3 && m.starts_with(second.to_uppercase().as_str()))
+    {
+        Some(index) => {
+            let day: i64 = fields
+                .next()
+                .ok_or_else(invalid)?
+                .parse()
+                .map_err(|_| invalid())?;
+            check_day(year, index as i64 + 1, day).ok_or_else(invalid)?;
+            first_day(index as i64 + 1, day)
+        }
+        None if second.len() == 3 && second.chars().all(|c| c.is_ascii_digit()) => {
+            let doy: i64 = second.parse().map_err(|_| invalid())?;
+            if !(1..=julian_day_number(year + 1, 1, 1) - first_day(1, 1)).contains(&doy) {
+                return Err(invalid());
+            }
+            first_day(1, 1) + doy - 1
+        }
+        None => {
+            let month: i64 = second.parse().map_err(|_| invalid())?;
+            let day: i64 = fields
+                .next()
+                .ok_or_else(invalid)?
+                .parse()
+                .map_err(|_| invalid())?;
+            check_day(year, month, day).ok_or_else(invalid)?;
+            first_day(month, day)
+        }
+    };
+
+    let (hour, minute, second) = match fields.next() {
+        Some(time) => {
+            let mut parts = time.split(':');
+            let mut integer = |max: i64| -> Result<i64, Error> {
+                match parts.next() {
+                    Some(p) => match p.parse::<i64>() {
+                        Ok(v) if (0..max).contains(&v) => Ok(v),
+                        _ => Err(invalid()),
+                    },
+                    None => Ok(0),
+                }
+            };
+            let hour = integer(24)?;
+            let minute = integer(60)?;
+            let second = match parts.next() {
+                Some(p) => match p.parse::<f64>() {
+                    Ok(v) if (0.0..61.0).contains(&v) => v,
+                    _ => return Err(invalid()),
+                },
+                None => 0.0,
+            };
+            if parts.next().is_some() {
+                return Err(invalid());
+            }
+            (hour, minute, second)
+        }
+        None => (0, 0, 0.0),
+    };
+
+    if fields.next().is_some() {
+        return Err(invalid());
+    }
+
+    // Whole seconds are exact, so that only the fraction of second may be rounded.
+    let whole = (jdn - J2000_JDN) * SPD + (hour - 12) * 3600 + minute * 60;
+
+    // A leap second shares its formal seconds with the next one, thus count from the second
+    // before, still under the previous number of leap seconds.
+    match second >= 60.0 {
+        true if hour == 23 && minute == 59 => Ok(((whole + 59) as f64, second - 59.0)),
+        true => Err(invalid()),
+        false => Ok((whole as f64 + second, 0.0)),
+    }
+}
+
+/// Julian day number of a date, in the Julian calendar before 1582 OCT 15 and Gregorian after.
+fn julian_day_number(year: i64, month: i64, day: i64) -> i64 {
+    let a = (14 - month) / 12;
+    let y = year + 4800 - a;
+    let m = month + 12 * a - 3;
+    let base = day + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4);
+
+    match (year, month, day) >= GREGORIAN_START {
+        true => base - y.div_euclid(100) + y.div_euclid(400) - 32045,
+        false => base - 32083,
+    }
+}
+
+/// Check that the day exists in the month.
+fn check_day(year: i64, month: i64, day: i64) -> Option<()> {
+    if !(1..=12).contains(&month) || day < 1 {
+        return None;
+    }
+    let next = match month {
+        12 => julian_day_number(year + 1, 1, 1),
+        _ => julian_day_number(year, month + 1, 1),
+    };
+    match day <= next - julian_day_number(year, month, 1) {
+        true => Some(()),
+        false => None,
+    }
+}
@@ -0,0 +1,156 @@
+/*!
+Limb and terminator points: the kinds of curves, the shadows and the cuts of their searches.
+*/
+
+use std::f64::consts::PI;
+
+/**
+Kind of the limb or terminator points, the first part of the `METHOD` strings of
+[`raw::limbpt`][crate::raw::limbpt] and [`raw::termpt`][crate::raw::termpt].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CurveType {
+    /// Points of tangency of the rays from the observer or of the planes tangent to the light
+    /// source, accurate for any shape.
+    #[default]
+    Tangent,
+    /// Points of the shape closest to the limb or terminator of its reference ellipsoid, faster
+    /// but only accurate for shapes close to it.
+    Guided,
+}
+
+impl CurveType {
+    /// String understood by CSPICE.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Tangent => "TANGENT",
+            Self::Guided => "GUIDED",
+        }
+    }
+}
+
+/**
+Shadow whose boundary is the terminator.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Shadow {
+    /// Boundary of the region where the light source is entirely hidden.
+    #[default]
+    Umbral,
+    /// Boundary of the region where the light source is partially visible.
+    Penumbral,
+}
+
+impl Shadow {
+    /// String understood by CSPICE.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Umbral => "UMBRAL",
+            Self::Penumbral => "PENUMBRAL",
+        }
+    }
+}
+
+/**
+Locus of the aberration corrections of the limb and terminator points.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CorrectionLocus {
+    /// The corrections of the target center apply to every point.
+    #[default]
+    Center,
+    /// The corrections are computed for each point of the limb or terminator of the reference
+    /// ellipsoid.
+    Ellipsoid,
+}
+
+impl CorrectionLocus {
+    /// String understood by [`raw::limbpt`][crate::raw::limbpt].
+    pub fn as_limb_str(&self) -> &'static str {
+        match self {
+            Self::Center => "CENTER",
+            Self::Ellipsoid => "ELLIPSOID LIMB",
+        }
+    }
+
+    /// String understood by [`raw::termpt`][crate::raw::termpt].
+    pub fn as_terminator_str(&self) -> &'static str {
+        match self {
+            Self::Center => "CENTER",
+            Self::Ellipsoid => "ELLIPSOID TERMINATOR",
+        }
+    }
+}
+
+/**
+Half-planes cutting the limb or the terminator, and the parameters of the search of the points.
+
+The first half-plane contains `refvec`, and the next ones are rotated by `rolstp` radians about
+the axis of the cuts.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cuts {
+    /// Reference vector of the first half-plane.
+    pub refvec: [f64; 3],
+    /// Rotation between two consecutive half-planes, in radians.
+    pub rolstp: f64,
+    /// Number of half-planes.
+    pub ncuts: usize,
+    /// Angular step of the search of the points, in radians.
+    pub schstp: f64,
+    /// Angular tolerance of the points, in radians.
+    pub soltol: f64,
+    /// Largest number of points, over all the cuts.
+    pub maxn: usize,
+}
+
+impl Cuts {
+    /// Cuts evenly spread over a full turn, searched with a step of 1e-4 and a tolerance of 1e-7
+    /// radians, with room for 100 points per cut.
+    pub fn new(refvec: [f64; 3], ncuts: usize) -> Self {
+        Self {
+            refvec,
+            rolstp: 2.0 * PI / ncuts.max(1) as f64,
+            ncuts,
+            schstp: 1e-4,
+            soltol: 1e-7,
+            maxn: 100 * ncuts,
+        }
+    }
+}
+
+/**
+Limb or terminator points found in a cut.
+*/
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Cut {
+    /// Points, in the body-fixed frame.
+    pub points: Vec<[f64; 3]>,
+    /// Epochs of the points, in TDB seconds past J2000.
+    pub epochs: Vec<f64>,
+    /// Tangent vectors from the observer to the points, in the body-fixed frame.
+    pub tangents: Vec<[f64; 3]>,
+}
+
+impl Cut {
+    /// Split the points returned for all the cuts, given the number of points of each cut.
+    pub fn split(
+        npts: &[i32],
+        points: &[[f64; 3]],
+        epochs: &[f64],
+        tangents: &[[f64; 3]],
+    ) -> Vec<Self> {
+        let mut start = 0;
+        npts.iter()
+            .map(|&n| {
+                let range = start..start + n.max(0) as usize;
+                start = range.end;
+                Self {
+                    points: points[range.clone()].to_vec(),
+                    epochs: epochs[range.clone()].to_vec(),
+                    tangents: tangents[range].to_vec(),
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,711 @@
+/*!
+Plate models in memory, exchanged with DSK segments and with the usual mesh files.
+*/
+
+use crate::{neat, raw, Error, DLADSC};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/**
+Mesh file formats.
+
+Wavefront OBJ and ASCII STL are text files. Binary PLY files are written little-endian, but both
+byte orders are read.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MeshFormat {
+    /// Wavefront OBJ.
+    Obj,
+    /// Polygon File Format, ASCII.
+    PlyAscii,
+    /// Polygon File Format, binary.
+    PlyBinary,
+    /// Stereolithography, ASCII.
+    StlAscii,
+    /// Stereolithography, binary.
+    StlBinary,
+}
+
+/**
+Plate model: vertices and triangular plates, as stored in type 2 DSK segments.
+
+The plates hold the 1-based indices of their vertices, as in CSPICE, so that a model is ready to
+be written with [`DskWriter`][crate::DskWriter]. The indices are converted when reading or writing
+files whose indices are 0-based, and the polygons of the files are split in triangles.
+
+The normals of the plates are optional when writing files, one per plate. They are computed from
+the vertices for STL files, which require them.
+
+The indices of a model built with [`PlateModel::new`] are not checked, [`PlateModel::validate`]
+does it. The writing methods return an error on invalid indices or normals, whereas the geometry
+methods such as [`PlateModel::normals`] panic.
+
+```ignore
+let model = spice::PlateModel::from_dsk(handle, dladsc);
+let normals = model.dsk_normals(handle, dladsc);
+model.save("dimorphos.ply", spice::MeshFormat::PlyBinary, Some(&normals))?;
+
+let model = spice::PlateModel::load("dimorphos.obj")?;
+spice::DskWriter::new(1, -658031, "DIMORPHOS_FIXED").write("dimorphos.bds", &model.vertices, &model.plates)?;
+```
+*/
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PlateModel {
+    /// Vertices.
+    pub vertices: Vec<[f64; 3]>,
+    /// 1-based indices of the vertices of each plate.
+    pub plates: Vec<[i32; 3]>,
+}
+
+impl PlateModel {
+    /// Plate model from its vertices and its plates of 1-based indices.
+    pub fn new(vertices: Vec<[f64; 3]>, plates: Vec<[i32; 3]>) -> Self {
+        Self { vertices, plates }
+    }
+
+    /// Plate model of a type 2 DSK segment, with [`neat::dskv02`] and [`neat::dskp02`].
+    pub fn from_dsk(handle: i32, dladsc: DLADSC) -> Self {
+        Self {
+            vertices: neat::dskv02(handle, dladsc),
+            plates: neat::dskp02(handle, dladsc),
+        }
+    }
+
+    /// Normals of the plates stored in a type 2 DSK segment, with [`raw::dskn02`].
+    pub fn dsk_normals(&self, handle: i32, dladsc: DLADSC) -> Vec<[f64; 3]> {
+        (1..=self.plates.len() as i32)
+            .map(|plid| raw::dskn02(handle, dladsc, plid))
+            .collect()
+    }
+
+    /// Unit normals of the plates, following the right-hand rule over their vertices.
+    pub fn normals(&self) -> Vec<[f64; 3]> {
+        self.plates
+            .iter()
+            .map(|plate| {
+                let [a, b, c] = self.corners(plate);
+                let ab = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+                let ac = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+                let normal = [
+                    ab[1] * ac[2] - ab[2] * ac[1],
+                    ab[2] * ac[0] - ab[0] * ac[2],
+                    ab[0] * ac[1] - ab[1] * ac[0],
+                ];
+                let norm = (normal[0].powi(2) + normal[1].powi(2) + normal[2].powi(2)).sqrt();
+                match norm > 0.0 {
+                    true => [normal[0] / norm, normal[1] / norm, normal[2] / norm],
+                    false => [0.0; 3],
+                }
+            })
+            .collect()
+    }
+
+    /// Check that the plates refer to existing vertices.
+    pub fn validate(&self) -> Result<(), Error> {
+        validate_plates(self.vertices.len(), &self.plates)
+    }
+
+    /// Check the plates, and that the normals, if given, are as many as them.
+    fn validate_normals(&self, normals: Option<&[[f64; 3]]>) -> Result<(), Error> {
+        self.validate()?;
+        match normals {
+            Some(normals) if normals.len() != self.plates.len() => Err(invalid(format!(
+                "{} normals given for {} plates",
+                normals.len(),
+                self.plates.len()
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Vertices of a plate.
+    pub(crate) fn corners(&self, plate: &[i32; 3]) -> [[f64; 3]; 3] {
+        [
+            self.vertices[plate[0] as usize - 1],
+            self.vertices[plate[1] as usize - 1],
+            self.vertices[plate[2] as usize - 1],
+        ]
+    }
+
+    /**
+    Read a mesh file, whose format is given by its extension: `obj`, `ply` or `stl`.
+
+    Both ASCII and binary PLY and STL files are read.
+    */
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let error = |e: String| Error::InvalidMesh(format!("{}: {}", path.display(), e));
+        let bytes = fs::read(path).map_err(|e| error(e.to_string()))?;
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_lowercase());
+
+        match extension.as_deref() {
+            Some("obj") => Self::read_obj(&bytes),
+            Some("ply") => Self::read_ply(&bytes),
+            Some("stl") => Self::read_stl(&bytes),
+            _ => Err(Error::InvalidMesh("unknown extension".to_string())),
+        }
+        .map_err(|e| match e {
+            Error::InvalidMesh(e) => error(e),
+            e => e,
+        })
+    }
+
+    /**
+    Write a mesh file in the given format, with the optional normals of the plates.
+
+    The model is checked before the file is created.
+    */
+    pub fn save<P: AsRef<Path>>(
+        &self,
+        path: P,
+        format: MeshFormat,
+        normals: Option<&[[f64; 3]]>,
+    ) -> Result<(), Error> {
+        self.validate_normals(normals)?;
+        let path = path.as_ref();
+        let error = |e: Error| match e {
+            Error::Io(e) => Error::Io(format!("{}: {}", path.display(), e)),
+            e => e,
+        };
+        let mut writer = BufWriter::new(fs::File::create(path).map_err(|e| error(e.into()))?);
+        match format {
+            MeshFormat::Obj => self.write_obj(&mut writer, normals),
+            MeshFormat::PlyAscii => self.write_ply(&mut writer, false, normals),
+            MeshFormat::PlyBinary => self.write_ply(&mut writer, true, normals),
+            MeshFormat::StlAscii => self.write_stl(&mut writer, false, normals),
+            MeshFormat::StlBinary => self.write_stl(&mut writer, true, normals),
+        }
+        .and_then(|_| writer.flush().map_err(Error::from))
+        .map_err(error)
+    }
+
+    /// Write the model as a Wavefront OBJ file, with one `vn` line per plate normal if given.
+    pub fn write_obj<W: Write>(
+        &self,
+        w: &mut W,
+        normals: Option<&[[f64; 3]]>,
+    ) -> Result<(), Error> {
+        self.validate_normals(normals)?;
+        for v in &self.vertices {
+            writeln!(w, "v {:e} {:e} {:e}", v[0], v[1], v[2])?;
+        }
+        match normals {
+            Some(normals) => {
+                for n in normals {
+                    writeln!(w, "vn {:e} {:e} {:e}", n[0], n[1], n[2])?;
+                }
+                for (k, p) in self.plates.iter().enumerate() {
+                    let k = k + 1;
+                    writeln!(w, "f {}//{} {}//{} {}//{}", p[0], k, p[1], k, p[2], k)?;
+                }
+            }
+            None => {
+                for p in &self.plates {
+                    writeln!(w, "f {} {} {}", p[0], p[1], p[2])?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /**
+    Write the model as a PLY file, ASCII or binary little-endian.
+
+    The coordinates are written as doubles, and the normals, if given, as the `nx`, `ny` and `nz`
+    properties of the faces.
+    */
+    pub fn write_ply<W: Write>(
+        &self,
+        w: &mut W,
+        binary: bool,
+        normals: Option<&[[f64; 3]]>,
+    ) -> Result<(), Error> {
+        self.validate_normals(normals)?;
+        let format = match binary {
+            true => "binary_little_endian",
+            false => "ascii",
+        };
+        writeln!(w, "ply\nformat {} 1.0", format)?;
+        writeln!(w, "element vertex {}", self.vertices.len())?;
+        writeln!(w, "property double x\nproperty double y\nproperty double z")?;
+        writeln!(w, "element face {}", self.plates.len())?;
+        writeln!(w, "property list uchar int vertex_indices")?;
+        if normals.is_some() {
+            writeln!(
+                w,
+                "property double nx\nproperty double ny\nproperty double nz"
+            )?;
+        }
+        writeln!(w, "end_header")?;
+
+        for v in &self.vertices {
+            match binary {
+                true => v.iter().try_for_each(|c| w.write_all(&c.to_le_bytes()))?,
+                false => writeln!(w, "{:e} {:e} {:e}", v[0], v[1], v[2])?,
+            }
+        }
+        for (k, p) in self.plates.iter().enumerate() {
+            let [a, b, c] = [p[0] - 1, p[1] - 1, p[2] - 1];
+            let normal = normals.map(|normals| normals[k]);
+            match binary {
+                true => {
+                    w.write_all(&[3])?;
+                    [a, b, c]
+                        .iter()
+                        .try_for_each(|i| w.write_all(&i.to_le_bytes()))?;
+                    if let Some(n) = normal {
+                        n.iter().try_for_each(|c| w.write_all(&c.to_le_bytes()))?;
+                    }
+                }
+                false => {
+                    write!(w, "3 {} {} {}", a, b, c)?;
+                    if let Some(n) = normal {
+                        write!(w, " {:e} {:e} {:e}", n[0], n[1], n[2])?;
+                    }
+                    writeln!(w)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /**
+    Write the model as a STL file, ASCII or binary.
+
+    The normals are computed from the vertices if they are not given. Binary files store the
+    coordinates as single precision floats.
+    */
+    pub fn write_stl<W: Write>(
+        &self,
+        w: &mut W,
+        binary: bool,
+        normals: Option<&[[f64; 3]]>,
+    ) -> Result<(), Error> {
+        self.validate_normals(normals)?;
+        let computed;
+        let normals = match normals {
+            Some(normals) => normals,
+            None => {
+                computed = self.normals();
+                &computed
+            }
+        };
+
+        match binary {
+            true => {
+                let mut header = [b' '; 80];
+                header[..10].copy_from_slice(b"rust-spice");
+                w.write_all(&header)?;
+                w.write_all(&(self.plates.len() as u32).to_le_bytes())?;
+                for (plate, normal) in self.plates.iter().zip(normals) {
+                    let [a, b, c] = self.corners(plate);
+                    for v in [normal, &a, &b, &c] {
+                        v.iter()
+                            .try_for_each(|c| w.write_all(&(*c as f32).to_le_bytes()))?;
+                    }
+                    w.write_all(&[0, 0])?;
+                }
+            }
+            false => {
+                writeln!(w, "solid rust-spice")?;
+                for (plate, n) in self.plates.iter().zip(normals) {
+                    writeln!(w, "facet normal {:e} {:e} {:e}", n[0], n[1], n[2])?;
+                    writeln!(w, "outer loop")?;
+                    for v in self.corners(plate) {
+                        writeln!(w, "vertex {:e} {:e} {:e}", v[0], v[1], v[2])?;
+                    }
+                    writeln!(w, "endloop\nendfacet")?;
+                }
+                writeln!(w, "endsolid rust-spice")?;
+            }
+        }
+        Ok(())
+    }
+
+    /**
+    Read a Wavefront OBJ file.
+
+    Only the vertices and the faces are read. The indices of the faces may be negative, relative
+    to the last vertex, and may refer to texture coordinates and normals, which are ignored.
+    */
+    pub fn read_obj(bytes: &[u8]) -> Result<Self, Error> {
+        let text = std::str::from_utf8(bytes).map_err(|e| invalid(e.to_string()))?;
+        let mut model = Self::default();
+
+        for (number, line) in text.lines().enumerate() {
+            let error = |e: &str| invalid(format!("line {}: {}", number + 1, e));
+            let mut tokens = line.split_whitespace();
+            match tokens.next() {
+                Some("v") => {
+                    let mut v = [0.0; 3];
+                    for c in v.iter_mut() {
+                        *c = tokens
+                            .next()
+                            .and_then(|t| t.parse().ok())
+                            .ok_or_else(|| error("invalid vertex"))?;
+                    }
+                    model.vertices.push(v);
+                }
+                Some("f") => {
+                    let nv = model.vertices.len() as i32;
+                    let polygon = tokens
+                        .map(|t| match t.split('/').next().unwrap().parse::<i32>() {
+                            Ok(i) if i > 0 && i <= nv => Ok(i),
+                            Ok(i) if i < 0 && -i <= nv => Ok(nv + 1 + i),
+                            _ => Err(error("invalid face")),
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    model.push_polygon(&polygon).map_err(error)?;
+                }
+                _ => (),
+            }
+        }
+        Ok(model)
+    }
+
+    /**
+    Read a PLY file, ASCII or binary of any byte order.
+
+    The coordinates are the `x`, `y` and `z` properties of the vertices and the polygons the
+    `vertex_indices` (or `vertex_index`) list of the faces. Other elements and properties are
+    skipped.
+    */
+    pub fn read_ply(bytes: &[u8]) -> Result<Self, Error> {
+        let end = b"end_header";
+        let header_end = bytes
+            .windows(end.len())
+            .position(|w| w == end)
+            .ok_or_else(|| invalid("missing end_header".to_string()))?;
+        let mut body = header_end + end.len();
+        while body < bytes.len() && (bytes[body] == b'\r' || bytes[body] == b'\n') {
+            body += 1;
+            if bytes[body - 1] == b'\n' {
+                break;
+            }
+        }
+        let header =
+            std::str::from_utf8(&bytes[..header_end]).map_err(|e| invalid(e.to_string()))?;
+
+        let mut lines = header.lines().map(str::trim);
+        if lines.next() != Some("ply") {
+            return Err(invalid("missing magic number".to_string()));
+        }
+        let mut encoding = None;
+        let mut elements: Vec<PlyElement> = vec![];
+        for line in lines {
+            let tokens = line.split_whitespace().collect::<Vec<_>>();
+            match tokens.as_slice() {
+                ["format", format, _] => {
+                    encoding = Some(match *format {
+                        "ascii" => PlyEncoding::Ascii,
+                        "binary_little_endian" => PlyEncoding::LittleEndian,
+                        "binary_big_endian" => PlyEncoding::BigEndian,
+                        _ => return Err(invalid(format!("unknown format `{}`", format))),
+                    })
+                }
+                ["element", name, count] => elements.push(PlyElement {
+                    name: name.to_string(),
+                    count: count
+                        .parse()
+                        .map_err(|_| invalid(format!("invalid count `{}`", count)))?,
+                    properties: vec![],
+                }),
+                ["property", "list", count, item, name] => elements
+                    .last_mut()
+                    .ok_or_else(|| invalid("property outside of an element".to_string()))?
+                    .properties
+                    .push(PlyProperty {
+                        name: name.to_string(),
+                        count: Some(PlyType::parse(count)?),
+                        kind: PlyType::parse(item)?,
+                    }),
+                ["property", kind, name] => elements
+                    .last_mut()
+                    .ok_or_else(|| invalid("property outside of an element".to_string()))?
+                    .properties
+                    .push(PlyProperty {
+                        name: name.to_string(),
+                        count: None,
+                        kind: PlyType::parse(kind)?,
+                    }),
+                _ => (),
+            }
+        }
+        let encoding = encoding.ok_or_else(|| invalid("missing format".to_string()))?;
+
+        let mut reader = PlyReader {
+            bytes: &bytes[body..],
+            position: 0,
+            encoding,
+        };
+        let mut model = Self::default();
+        for element in &elements {
+            let position = |name: &str| element.properties.iter().position(|p| p.name == name);
+            let xyz = [position("x"), position("y"), position("z")];
+            let indices = position("vertex_indices").or_else(|| position("vertex_index"));
+
+            for _ in 0..element.count {
+                let mut values = Vec::with_capacity(element.properties.len());
+                for property in &element.properties {
+                    values.push(match property.count {
+                        Some(count) => {
+                            let n = reader.read(count)? as usize;
+                            (0..n)
+                                .map(|_| reader.read(property.kind))
+                                .collect::<Result<Vec<_>, _>>()?
+                        }
+                        None => vec![reader.read(property.kind)?],
+                    });
+                }
+                match element.name.as_str() {
+                    "vertex" => {
+                        let mut v = [0.0; 3];
+                        for (c, i) in v.iter_mut().zip(xyz) {
+                            *c = values[i.ok_or_else(|| invalid("missing coordinate".into()))?][0];
+                        }
+                        model.vertices.push(v);
+                    }
+                    "face" => {
+                        let i = indices.ok_or_else(|| invalid("missing vertex indices".into()))?;
+                        let polygon = values[i].iter().map(|i| *i as i32 + 1).collect::<Vec<_>>();
+                        model
+                            .push_polygon(&polygon)
+                            .map_err(|e| invalid(e.to_string()))?;
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        let nv = model.vertices.len() as i32;
+        match model.plates.iter().flatten().all(|&i| i >= 1 && i <= nv) {
+            true => Ok(model),
+            false => Err(invalid("vertex index out of range".to_string())),
+        }
+    }
+
+    /**
+    Read a STL file, ASCII or binary.
+
+    The vertices shared by several facets are merged, so that the model has as many vertices as
+    distinct coordinates.
+    */
+    pub fn read_stl(bytes: &[u8]) -> Result<Self, Error> {
+        let mut model = Self::default();
+        let mut indices = HashMap::new();
+        let mut push = |v: [f64; 3]| {
+            let key = [v[0].to_bits(), v[1].to_bits(), v[2].to_bits()];
+            *indices.entry(key).or_insert_with(|| {
+                model.vertices.push(v);
+                model.vertices.len() as i32
+            })
+        };
+        let mut plates = vec![];
+
+        let ascii = bytes.starts_with(b"solid")
+            && std::str::from_utf8(bytes).is_ok_and(|text| text.contains("facet"));
+        if ascii {
+            let text = std::str::from_utf8(bytes).unwrap();
+            let mut polygon = vec![];
+            let mut tokens = text.split_whitespace();
+            while let Some(token) = tokens.next() {
+                match token {
+                    "vertex" => {
+                        let mut v = [0.0; 3];
+                        for c in v.iter_mut() {
+                            *c = tokens
+                                .next()
+                                .and_then(|t| t.parse().ok())
+                                .ok_or_else(|| invalid("invalid vertex".to_string()))?;
+                        }
+                        polygon.push(push(v));
+                    }
+                    "endloop" => {
+                        if polygon.len() != 3 {
+                            return Err(invalid("facet without 3 vertices".to_string()));
+                        }
+                        plates.push([polygon[0], polygon[1], polygon[2]]);
+                        polygon.clear();
+                    }
+                    _ => (),
+                }
+            }
+        } else {
+            let count = bytes
+                .get(80..84)
+                .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+                .ok_or_else(|| invalid("truncated header".to_string()))?;
+            if bytes.len() < 84 + 50 * count {
+                return Err(invalid("truncated facets".to_string()));
+            }
+            for facet in bytes[84..84 + 50 * count].chunks(50) {
+                let coordinate = |k: usize| {
+                    let b = &facet[4 * k..4 * k + 4];
+                    f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64
+                };
+                let mut plate = [0; 3];
+                for (i, index) in plate.iter_mut().enumerate() {
+                    let k = 3 * (i + 1);
+                    *index = push([coordinate(k), coordinate(k + 1), coordinate(k + 2)]);
+                }
+                plates.push(plate);
+            }
+        }
+
+        model.plates = plates;
+        Ok(model)
+    }
+
+    /// Split a polygon of 1-based indices into plates sharing its first vertex.
+    fn push_polygon(&mut self, polygon: &[i32]) -> Result<(), &'static str> {
+        if polygon.len() < 3 {
+            return Err("face with less than 3 vertices");
+        }
+        for k in 1..polygon.len() - 1 {
+            self.plates.push([polygon[0], polygon[k], polygon[k + 1]]);
+        }
+        Ok(())
+    }
+}
+
+/// Error on a malformed mesh.
+/// Check that the 1-based plates refer to existing vertices, out of `count`.
+pub(crate) fn validate_plates(count: usize, plates: &[[i32; 3]]) -> Result<(), Error> {
+    match plates
+        .iter()
+        .enumerate()
+        .find(|(_, plate)| plate.iter().any(|&i| i < 1 || i as usize > count))
+    {
+        Some((k, plate)) => Err(invalid(format!(
+            "plate {} refers to vertices {:?} out of 1..={}",
+            k + 1,
+            plate,
+            count
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn invalid(e: String) -> Error {
+    Error::InvalidMesh(e)
+}
+
+/// Encoding of the data of a PLY file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlyEncoding {
+    Ascii,
+    LittleEndian,
+    BigEndian,
+}
+
+/// Scalar types of PLY properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlyType {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl PlyType {
+    fn parse(s: &str) -> Result<Self, Error> {
+        Ok(match s {
+            "char" | "int8" => Self::I8,
+            "uchar" | "uint8" => Self::U8,
+            "short" | "int16" => Self::I16,
+            "ushort" | "uint16" => Self::U16,
+            "int" | "int32" => Self::I32,
+            "uint" | "uint32" => Self::U32,
+            "float" | "float32" => Self::F32,
+            "double" | "float64" => Self::F64,
+            _ => return Err(invalid(format!("unknown property type `{}`", s))),
+        })
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            Self::I8 | Self::U8 => 1,
+            Self::I16 | Self::U16 => 2,
+            Self::I32 | Self::U32 | Self::F32 => 4,
+            Self::F64 => 8,
+        }
+    }
+}
+
+/// Property of a PLY element, a list if it has a count type.
+#[derive(Debug, Clone)]
+struct PlyProperty {
+    name: String,
+    count: Option<PlyType>,
+    kind: PlyType,
+}
+
+/// Element of a PLY file.
+#[derive(Debug, Clone)]
+struct PlyElement {
+    name: String,
+    count: usize,
+    properties: Vec<PlyProperty>,
+}
+
+/// Reader of the values of the body of a PLY file.
+struct PlyReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    encoding: PlyEncoding,
+}
+
+impl PlyReader<'_> {
+    fn read(&mut self, kind: PlyType) -> Result<f64, Error> {
+        match self.encoding {
+            PlyEncoding::Ascii => {
+                let rest = &self.bytes[self.position..];
+                let start = rest
+                    .iter()
+                    .position(|b| !b.is_ascii_whitespace())
+                    .ok_or_else(|| invalid("truncated data".to_string()))?;
+                let length = rest[start..]
+                    .iter()
+                    .position(|b| b.is_ascii_whitespace())
+                    .unwrap_or(rest.len() - start);
+                self.position += start + length;
+                std::str::from_utf8(&rest[start..start + length])
+                    .ok()
+                    .and_then(|t| t.parse().ok())
+                    .ok_or_else(|| invalid("invalid value".to_string()))
+            }
+            encoding => {
+                let size = kind.size();
+                let mut b = [0; 8];
+                b[..size].copy_from_slice(
+                    self.bytes
+                        .get(self.position..self.position + size)
+                        .ok_or_else(|| invalid("truncated data".to_string()))?,
+                );
+                self.position += size;
+                if encoding == PlyEncoding::BigEndian {
+                    b[..size].reverse();
+                }
+                Ok(match kind {
+                    PlyType::I8 => b[0] as i8 as f64,
+                    PlyType::U8 => b[0] as f64,
+                    PlyType::I16 => i16::from_le_bytes([b[0], b[1]]) as f64,
+                    PlyType::U16 => u16::from_le_bytes([b[0], b[1]]) as f64,
+                    PlyType::I32 => i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+                    PlyType::U32 => u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+                    PlyType::F32 => f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+                    PlyType::F64 => f64::from_le_bytes(b),
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,241 @@
+/*!
+Computation methods of the geometry functions: the shape of the target and the kind of
+sub-point.
+*/
+
+use crate::{Error, Surface};
+use std::fmt;
+use std::str::FromStr;
+
+/**
+Shape of the target, the `METHOD` string of [`raw::sincpt`][crate::raw::sincpt],
+[`raw::illumf`][crate::raw::illumf], [`raw::latsrf`][crate::raw::latsrf] and the other geometry
+functions.
+
+The shape renders the `METHOD` strings of CSPICE, such as `ELLIPSOID` or
+`DSK/UNPRIORITIZED/SURFACES = 1, 2`. Parsing ignores case and blanks, and accepts the terms of a
+DSK shape in any order, just like CSPICE does, but only surfaces given by their IDs: use
+[`Surface::from_name`] to translate their names.
+
+For backward compatibility, every function taking a [`ShapeModel`] also accepts strings. The
+strings which cannot be parsed, such as the ones naming surfaces, are kept as
+[`ShapeModel::Raw`] and left for CSPICE to check.
+
+```ignore
+let shape = spice::ShapeModel::dsk(&[low]);
+let (spoint, trgepc, srfvec, found) =
+    spice::sincpt(&shape, "DIMORPHOS", et, "DIMORPHOS_FIXED", "NONE", "HERA", "J2000", dvec);
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum ShapeModel {
+    /// Triaxial ellipsoid of the radii of the target in the kernel pool.
+    #[default]
+    Ellipsoid,
+    /// Plates of the loaded DSK segments of the given surfaces, or of every surface if none is
+    /// given, without priority between the segments.
+    Dsk {
+        /// IDs of the surfaces.
+        surfaces: Vec<i32>,
+    },
+    /// `METHOD` string sent to CSPICE as is.
+    Raw(String),
+}
+
+impl ShapeModel {
+    /// DSK shape of the given surfaces, or of every surface if none is given.
+    pub fn dsk(surfaces: &[Surface]) -> Self {
+        Self::Dsk {
+            surfaces: surfaces.iter().map(|surface| surface.id()).collect(),
+        }
+    }
+
+    /// Whether the shape is made of DSK data, which is guessed from the string of a raw shape.
+    pub fn is_dsk(&self) -> bool {
+        match self {
+            Self::Ellipsoid => false,
+            Self::Dsk { .. } => true,
+            Self::Raw(method) => method.to_uppercase().contains("DSK"),
+        }
+    }
+}
+
+impl fmt::Display for ShapeModel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Ellipsoid => f.write_str("ELLIPSOID"),
+            Self::Dsk { surfaces } if surfaces.is_empty() => f.write_str("DSK/UNPRIORITIZED"),
+            Self::Dsk { surfaces } => write!(
+                f,
+                "DSK/UNPRIORITIZED/SURFACES = {}",
+                surfaces
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::Raw(method) => f.write_str(method),
+        }
+    }
+}
+
+impl FromStr for ShapeModel {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidMethod(s.to_string());
+        let terms = s.split('/').map(squeeze).collect::<Vec<_>>();
+
+        if terms == ["ELLIPSOID"] {
+            return Ok(Self::Ellipsoid);
+        }
+
+        // The DSK terms may come in any order, but only once each.
+        let (mut dsk, mut unprioritized, mut surfaces) = (false, false, None);
+        for term in &terms {
+            match term.as_str() {
+                "DSK" if !dsk => dsk = true,
+                "UNPRIORITIZED" if !unprioritized => unprioritized = true,
+                _ if surfaces.is_none() => {
+                    let list = term.strip_prefix("SURFACES=").ok_or_else(invalid)?;
+                    surfaces = Some(
+                        list.split(',')
+                            .map(|id| id.parse::<i32>().map_err(|_| invalid()))
+                            .collect::<Result<Vec<_>, _>>()?,
+                    );
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        match dsk && unprioritized {
+            true => Ok(Self::Dsk {
+                surfaces: surfaces.unwrap_or_default(),
+            }),
+            false => Err(invalid()),
+        }
+    }
+}
+
+impl From<&ShapeModel> for ShapeModel {
+    fn from(shape: &ShapeModel) -> Self {
+        shape.clone()
+    }
+}
+
+impl From<&str> for ShapeModel {
+    fn from(s: &str) -> Self {
+        s.parse().unwrap_or_else(|_| Self::Raw(s.to_string()))
+    }
+}
+
+impl From<&String> for ShapeModel {
+    fn from(s: &String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<String> for ShapeModel {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+/**
+Kind of sub-observer or sub-solar point on a shape, the `METHOD` string of
+[`raw::subpnt`][crate::raw::subpnt] and [`raw::subslr`][crate::raw::subslr].
+
+The method renders the `METHOD` strings of CSPICE, such as `NEAR POINT/ELLIPSOID` or
+`INTERCEPT/DSK/UNPRIORITIZED`. The near point of a DSK shape is rendered as its `NADIR`, since
+CSPICE only finds the near point of ellipsoids. Parsing rejects the combinations that CSPICE
+rejects, such as `NEAR POINT/DSK/UNPRIORITIZED` or `NADIR/ELLIPSOID`, and otherwise behaves as
+the one of [`ShapeModel`]. Like the latter, the strings which cannot be parsed, such as the legacy
+`Near point: ellipsoid`, are kept as [`SubPointMethod::Raw`] by the conversions from strings.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SubPointMethod {
+    /// Point of the shape closest to the observer or to the Sun: the nearest point of an
+    /// ellipsoid, or the intercept of the line from the observer to the nearest point of the
+    /// reference ellipsoid for a DSK shape.
+    NearPoint(ShapeModel),
+    /// Intercept of the line from the observer or from the Sun to the center of the target.
+    Intercept(ShapeModel),
+    /// `METHOD` string sent to CSPICE as is.
+    Raw(String),
+}
+
+impl SubPointMethod {
+    /// Shape of the target, unknown for a raw method.
+    pub fn shape(&self) -> Option<&ShapeModel> {
+        match self {
+            Self::NearPoint(shape) | Self::Intercept(shape) => Some(shape),
+            Self::Raw(_) => None,
+        }
+    }
+}
+
+impl Default for SubPointMethod {
+    fn default() -> Self {
+        Self::NearPoint(ShapeModel::Ellipsoid)
+    }
+}
+
+impl fmt::Display for SubPointMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NearPoint(shape) if shape.is_dsk() => write!(f, "NADIR/{}", shape),
+            Self::NearPoint(shape) => write!(f, "NEAR POINT/{}", shape),
+            Self::Intercept(shape) => write!(f, "INTERCEPT/{}", shape),
+            Self::Raw(method) => f.write_str(method),
+        }
+    }
+}
+
+impl FromStr for SubPointMethod {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidMethod(s.to_string());
+        let (kind, shape) = s.split_once('/').unwrap_or((s, "ELLIPSOID"));
+        let shape = shape.parse::<ShapeModel>().map_err(|_| invalid())?;
+
+        match (squeeze(kind).as_str(), shape.is_dsk()) {
+            ("NEARPOINT", false) => Ok(Self::NearPoint(shape)),
+            ("NADIR", true) => Ok(Self::NearPoint(shape)),
+            ("INTERCEPT", _) => Ok(Self::Intercept(shape)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl From<&SubPointMethod> for SubPointMethod {
+    fn from(method: &SubPointMethod) -> Self {
+        method.clone()
+    }
+}
+
+impl From<&str> for SubPointMethod {
+    fn from(s: &str) -> Self {
+        s.parse().unwrap_or_else(|_| Self::Raw(s.to_string()))
+    }
+}
+
+impl From<&String> for SubPointMethod {
+    fn from(s: &String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<String> for SubPointMethod {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+/// Uppercase term without its blanks.
+fn squeeze(term: &str) -> String {
+    term.chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase()
+}
@@ -17,26 +17,59 @@ the source of this lib to deal with the FFI types and unsafe code.
 
 CSPICE | **rust-spice** | Description
 -------|--------------|------------
+[axisar_c][axisar_c link] | [`raw::axisar`] | Axis and angle to rotation
+[azlrec_c][azlrec_c link] | [`raw::azlrec`] | Range, az and el to rectangular coordinates
 [bodc2n_c][bodc2n_c link] | [`neat::bodc2n`] | Body ID code to name translation
+[boddef_c][boddef_c link] | [`raw::boddef`] | Body name/ID definition
 [bodfnd_c][bodfnd_c link] | [`raw::bodfnd`] | Find values from the kernel pool
 [bodn2c_c][bodn2c_c link] | [`raw::bodn2c`] | Body name to ID code translation
+[bodvcd_c][bodvcd_c link] | [`raw::bodvcd`] | Return d.p. values from the kernel pool, by ID
 [bodvrd_c][bodvrd_c link] | [`raw::bodvrd`] | Return d.p. values from the kernel pool
 [ckcov_c][ckcov_c link] | *TODO*
 [ckgp_c][ckgp_c link] | *TODO*
 [ckgpav_c][ckgpav_c link] | *TODO*
 [ckobj_c][ckobj_c link] | *TODO*
+[cylrec_c][cylrec_c link] | [`raw::cylrec`] | Cylindrical to rectangular coordinates
 [dascls_c][dascls_c link] | [`raw::dascls`] | DAS, close file
 [dasopr_c][dasopr_c link] | [`raw::dasopr`] | DAS, open for read
+[dcyldr_c][dcyldr_c link] | [`raw::dcyldr`] | Derivative of cylindrical w.r.t. rectangular
 [deltet_c][deltet_c link] | [`raw::udeltet`] | Delta ET, ET - UTC
+[dgeodr_c][dgeodr_c link] | [`raw::dgeodr`] | Derivative of geodetic w.r.t. rectangular
+[dlabbs_c][dlabbs_c link] | [`raw::dlabbs`] | DLA, begin backward search
 [dlabfs_c][dlabfs_c link] | [`raw::dlabfs`] | DLA, begin forward search
+[dlafns_c][dlafns_c link] | [`raw::dlafns`] | DLA, find next segment
+[dlafps_c][dlafps_c link] | [`raw::dlafps`] | DLA, find previous segment
+[dlatdr_c][dlatdr_c link] | [`raw::dlatdr`] | Derivative of latitudinal w.r.t. rectangular
+[dpgrdr_c][dpgrdr_c link] | [`raw::dpgrdr`] | Derivative of planetographic w.r.t. rectangular
+[drdcyl_c][drdcyl_c link] | [`raw::drdcyl`] | Derivative of rectangular w.r.t. cylindrical
+[drdgeo_c][drdgeo_c link] | [`raw::drdgeo`] | Derivative of rectangular w.r.t. geodetic
+[drdlat_c][drdlat_c link] | [`raw::drdlat`] | Derivative of rectangular w.r.t. latitudinal
+[drdpgr_c][drdpgr_c link] | [`raw::drdpgr`] | Derivative of rectangular w.r.t. planetographic
+[drdsph_c][drdsph_c link] | [`raw::drdsph`] | Derivative of rectangular w.r.t. spherical
+[dskb02_c][dskb02_c link] | [`raw::dskb02`] | DSK, fetch type 2 bookkeeping data
+[dskcls_c][dskcls_c link] | [`raw::dskcls`] | DSK, close file
+[dskd02_c][dskd02_c link] | [`neat::dskd02`] | DSK, fetch d.p. type 2 data
 [dskgd_c][dskgd_c link] | [`raw::dskgd`] | DSK, return DSK segment descriptor
+[dski02_c][dski02_c link] | [`neat::dski02`] | DSK, fetch integer type 2 data
+[dskmi2_c][dskmi2_c link] | [`raw::dskmi2`] | DSK, make spatial index for type 2 segment
 [dskn02_c][dskn02_c link] | [`raw::dskn02`] | DSK, type 2, compute normal vector for plate
 [dskobj_c][dskobj_c link] | [`raw::dskobj`] | DSK, get object IDs
+[dskopn_c][dskopn_c link] | [`raw::dskopn`] | DSK, open new file
 [dskp02_c][dskp02_c link] | [`neat::dskp02`] | DSK, fetch type 2 plate data
+[dskrb2_c][dskrb2_c link] | [`raw::dskrb2`] | DSK, determine range bounds for plate set
 [dsksrf_c][dsksrf_c link] | *TODO*
 [dskv02_c][dskv02_c link] | [`neat::dskv02`] | DSK, fetch type 2 vertex data
+[dskw02_c][dskw02_c link] | [`raw::dskw02`] | DSK, write type 2 segment
 [dskx02_c][dskx02_c link] | [`raw::dskx02`] | DSK, ray-surface intercept, type 2
+[dskxsi_c][dskxsi_c link] | [`raw::dskxsi`] | DSK, ray-surface intercept with source information
+[dskxv_c][dskxv_c link] | [`raw::dskxv`] | DSK, ray-surface intercept, vectorized
 [dskz02_c][dskz02_c link] | [`raw::dskz02`] | DSK, fetch type 2 model size parameters
+[dsphdr_c][dsphdr_c link] | [`raw::dsphdr`] | Derivative of spherical w.r.t. rectangular
+[dtpool_c][dtpool_c link] | [`raw::dtpool`] | Return information about a kernel pool variable
+[edlimb_c][edlimb_c link] | [`raw::edlimb`] | Ellipsoid Limb
+[et2utc_c][et2utc_c link] | [`neat::et2utc`] | Ephemeris Time to UTC
+[eul2m_c][eul2m_c link] | [`raw::eul2m`] | Euler angles to matrix
+[frmnam_c][frmnam_c link] | [`neat::frmnam`] | Frame to Name
 [furnsh_c][furnsh_c link] | [`raw::furnsh`] | Furnish a program with SPICE kernels
 [gcpool_c][gcpool_c link] | *TODO*
 [gdpool_c][gdpool_c link] | [`raw::gdpool`] | Get d.p. values from the kernel pool
@@ -44,16 +77,33 @@ CSPICE | **rust-spice** | Description
 [getfov_c][getfov_c link] | [`raw::getfov`] | Get instrument FOV parameters
 [gipool_c][gipool_c link] | *TODO*
 [illumf_c][illumf_c link] | [`raw::illumf`] | Illumination angles, general source, return flags
+[illumg_c][illumg_c link] | [`neat::illumg`] | Illumination angles, general source
+[ilumin_c][ilumin_c link] | [`neat::ilumin`] | Illumination angles
+[inedpl_c][inedpl_c link] | [`raw::inedpl`] | Intersection of ellipsoid and plane
+[inrypl_c][inrypl_c link] | [`raw::inrypl`] | Intersection of ray and plane
 [kclear_c][kclear_c link] | [`raw::kclear`] | Keeper clear
 [kdata_c][kdata_c link] | [`neat::kdata`] | Kernel Data
 [ktotal_c][ktotal_c link] | [`raw::ktotal`] | Kernel Totals
 [latrec_c][latrec_c link] | [`raw::latrec`] | Latitudinal to rectangular coordinates
-[latsrf_c][latsrf_c link] | *TODO*
+[latsrf_c][latsrf_c link] | [`raw::latsrf`] | Latitudinal grid to surface points
+[limbpt_c][limbpt_c link] | [`neat::limbpt`] | Limb points on an extended object
+[m2eul_c][m2eul_c link] | [`raw::m2eul`] | Matrix to Euler angles
+[m2q_c][m2q_c link] | [`raw::m2q`] | Matrix to quaternion
 [mxv_c][mxv_c link] | [`raw::mxv`] |  Matrix times vector, 3x3
+[nearpt_c][nearpt_c link] | [`raw::nearpt`] | Nearest point on an ellipsoid
+[npedln_c][npedln_c link] | [`raw::npedln`] | Nearest point on ellipsoid to line
+[npelpt_c][npelpt_c link] | [`raw::npelpt`] | Nearest point on ellipse to point
+[nvc2pl_c][nvc2pl_c link] | [`raw::nvc2pl`] | Normal vector and constant to plane
+[nvp2pl_c][nvp2pl_c link] | [`raw::nvp2pl`] | Normal vector and point to plane
 [occult_c][occult_c link] | [`raw::occult`] | Find occultation type at time
+[pgrrec_c][pgrrec_c link] | [`raw::pgrrec`] | Planetographic to rectangular
 [pckcov_c][pckcov_c link] | *TODO*
+[phaseq_c][phaseq_c link] | [`raw::phaseq`] | Phase angle quantity between bodies centers
+[pl2nvc_c][pl2nvc_c link] | [`raw::pl2nvc`] | Plane to normal vector and constant
+[psv2pl_c][psv2pl_c link] | [`raw::psv2pl`] | Point and spanning vectors to plane
 [pxform_c][pxform_c link] | [`raw::pxform`] | Position Transformation Matrix
 [pxfrm2_c][pxfrm2_c link] | [`raw::pxfrm2`] | Position Transform Matrix, Different Epochs
+[q2m_c][q2m_c link] | [`raw::q2m`] | Quaternion to matrix
 [sce2c_c][sce2c_c link] | *TODO*
 [sce2s_c][sce2s_c link] | *TODO*
 [scencd_c][scencd_c link] | *TODO*
@@ -61,57 +111,110 @@ CSPICE | **rust-spice** | Description
 [scs2e_c][scs2e_c link] | *TODO*
 [sct2e_c][sct2e_c link] | *TODO*
 [sincpt_c][sincpt_c link] | [`raw::sincpt`] | Surface intercept
+[sphrec_c][sphrec_c link] | [`raw::sphrec`] | Spherical to rectangular coordinates
 [spkcls_c][spkcov_c link] | [`raw::spkcls`] | SPK, Close file
 [spkcov_c][spkcov_c link] | *TODO*
 [spkcpo_c][spkcpo_c link] | *TODO*
 [spkcpt_c][spkcpt_c link] | *TODO*
 [spkcvo_c][spkcvo_c link] | *TODO*
 [spkcvt_c][spkcvt_c link] | *TODO*
+[spkacs_c][spkacs_c link] | [`raw::spkacs`] | S/P Kernel, aberration corrected state
+[spkapo_c][spkapo_c link] | [`raw::spkapo`] | S/P Kernel, apparent position only
+[spkez_c][spkez_c link] | [`raw::spkez`] | S/P Kernel, easy reader
+[spkezp_c][spkezp_c link] | [`raw::spkezp`] | S/P Kernel, easy position
 [spkezr_c][spkezr_c link] | [`raw::spkezr`] | S/P Kernel, easier reader
+[spkgeo_c][spkgeo_c link] | [`raw::spkgeo`] | S/P Kernel, geometric state
+[spkgps_c][spkgps_c link] | [`raw::spkgps`] | S/P Kernel, geometric position
 [spkobj_c][spkobj_c link] | *TODO*
 [spkopn_c][spkopn_c link] | [`raw::spkopn`] | SPK, open new file.
 [spkpos_c][spkpos_c link] | [`raw::spkpos`] | S/P Kernel, position
+[spkssb_c][spkssb_c link] | [`raw::spkssb`] | S/P Kernel, solar system barycenter
 [spkw09_c][spkopn_c link] | [`raw::spkw09`] | Write SPK segment, type 9
-[srfc2s_c][srfc2s_c link] | *TODO*
-[srfcss_c][srfcss_c link] | *TODO*
-[srfnrm_c][srfnrm_c link] | *TODO*
-[srfs2c_c][srfs2c_c link] | *TODO*
-[srfscc_c][srfscc_c link] | *TODO*
+[srfc2s_c][srfc2s_c link] | [`neat::srfc2s`] | Surface and body ID codes to surface string
+[srfcss_c][srfcss_c link] | [`neat::srfcss`] | Surface ID and body string to surface string
+[srfnrm_c][srfnrm_c link] | [`raw::srfnrm`] | Map surface points to outward normal vectors
+[srfs2c_c][srfs2c_c link] | [`raw::srfs2c`] | Surface and body strings to surface ID code
+[srfscc_c][srfscc_c link] | [`raw::srfscc`] | Surface string and body ID code to surface ID code
 [str2et_c][str2et_c link] | [`raw::str2et`] | String to ET
 [sunpnt_c][sxform_c link] | [`raw::subpnt`] | Sub-observer point
 [surfpt_c][surfpt_c link] | [`raw::surfpt`] | Surface point on an ellipsoid
 [sxform_c][sxform_c link] | *TODO*
 [radrec_c][radrec_c link] | [`raw::radrec`] |  RA and DEC to rectangular coordinates
+[raxisa_c][raxisa_c link] | [`raw::raxisa`] | Rotation axis of a matrix
+[recazl_c][recazl_c link] | [`raw::recazl`] | Rectangular coordinates to range, az and el
+[reccyl_c][reccyl_c link] | [`raw::reccyl`] | Rectangular to cylindrical coordinates
+[recgeo_c][recgeo_c link] | [`raw::recgeo`] | Rectangular to geodetic
+[reclat_c][reclat_c link] | [`raw::reclat`] | Rectangular to latitudinal coordinates
 [recrad_c][recrad_c link] | [`raw::recrad`] | Rectangular coordinates to RA and DEC
 [recpgr_c][recpgr_c link] | [`raw::recpgr`] | Rectangular to planetographic
+[recsph_c][recsph_c link] | [`raw::recsph`] | Rectangular to spherical coordinates
+[saelgv_c][saelgv_c link] | [`raw::saelgv`] | Semi-axes of ellipse from generating vectors
+[subslr_c][subslr_c link] | [`neat::subslr`] | Sub-solar point
+[surfnm_c][surfnm_c link] | [`raw::surfnm`] | Surface normal vector on an ellipsoid
+[termpt_c][termpt_c link] | [`neat::termpt`] | Terminator points on an extended object
 [timout_c][timout_c link] | [`neat::timout`] | Time Output
+[tpictr_c][tpictr_c link] | [`neat::tpictr`] | Create a time format picture
 [unitim_c][unitim_c link] | [`raw::unitime`] | Uniform time scale transformation
 [unload_c][unload_c link] | [`raw::unload`] | Unload a kernel
 [vcrss_c][vcrss_c link] | [`raw::vcrss`] | Vector cross product, 3 dimensions
 [vdot_c][vdot_c link] | [`raw::vdot`] |  Vector dot product, 3 dimensions
 [vsep_c][vsep_c link] | [`raw::vsep`] | Angular separation of vectors, 3 dimensions
+[xfmsta_c][xfmsta_c link] | [`raw::xfmsta`] | Transform state between coordinate systems
 [xpose_c][xpose_c link] | [`raw::xpose`] | Transpose a matrix, 3x3
 
+[axisar_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/axisar_c.html
+[azlrec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/azlrec_c.html
 [bodc2n_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/bodc2n_c.html
+[boddef_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/boddef_c.html
 [bodfnd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/bodfnd_c.html
 [bodn2c_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/bodn2c_c.html
+[bodvcd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/bodvcd_c.html
 [bodvrd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/bodvrd_c.html
 [ckcov_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ckcov_c.html
 [ckgp_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ckgp_c.html
 [ckgpav_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ckgpav_c.html
 [ckobj_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ckobj_c.html
+[cylrec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/cylrec_c.html
 [dascls_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dascls_c.html
 [dasopr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dasopr_c.html
+[dcyldr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dcyldr_c.html
 [deltet_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/deltet_c.html
+[dgeodr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dgeodr_c.html
+[dlabbs_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dlabbs_c.html
 [dlabfs_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dasopr_c.html
+[dlafns_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dlafns_c.html
+[dlafps_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dlafps_c.html
+[dlatdr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dlatdr_c.html
+[dpgrdr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dpgrdr_c.html
+[drdcyl_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/drdcyl_c.html
+[drdgeo_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/drdgeo_c.html
+[drdlat_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/drdlat_c.html
+[drdpgr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/drdpgr_c.html
+[drdsph_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/drdsph_c.html
+[dskb02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskb02_c.html
+[dskcls_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskcls_c.html
+[dskd02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskd02_c.html
 [dskgd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskgd_c.html
+[dski02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dski02_c.html
+[dskmi2_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskmi2_c.html
 [dskn02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskn02_c.html
 [dskobj_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskobj_c.html
+[dskopn_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskopn_c.html
 [dskp02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskp02_c.html
+[dskrb2_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskrb2_c.html
 [dsksrf_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dsksrf_c.html
 [dskv02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskv02_c.html
+[dskw02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskw02_c.html
 [dskx02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskx02_c.html
+[dskxsi_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskxsi_c.html
+[dskxv_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskxv_c.html
 [dskz02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskz02_c.html
+[dsphdr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dsphdr_c.html
+[dtpool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dtpool_c.html
+[edlimb_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/edlimb_c.html
+[et2utc_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/et2utc_c.html
+[eul2m_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/eul2m_c.html
+[frmnam_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/frmnam_c.html
 [furnsh_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/furnsh_c.html
 [gcpool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gcpool_c.html
 [gdpool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gdpool_c.html
@@ -119,17 +222,36 @@ CSPICE | **rust-spice** | Description
 [georec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/georec_c.html
 [gipool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gipool_c.html
 [illumf_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/illumf_c.html
+[illumg_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/illumg_c.html
+[ilumin_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ilumin_c.html
+[inedpl_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/inedpl_c.html
+[inrypl_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/inrypl_c.html
 [kclear_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/kclear_c.html
 [kdata_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/kdata_c.html
 [ktotal_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ktotal_c.html
 [latrec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/latrec_c.html
 [latsrf_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/latsrf_c.html
+[limbpt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/limbpt_c.html
+[m2eul_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/m2eul_c.html
+[m2q_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/m2q_c.html
 [mxv_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/mxv_c.html
+[nearpt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/nearpt_c.html
+[npedln_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/npedln_c.html
+[npelpt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/npelpt_c.html
+[nvc2pl_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/nvc2pl_c.html
+[nvp2pl_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/nvp2pl_c.html
 [occult_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/occult_c.html
+[phaseq_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/phaseq_c.html
+[pl2nvc_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pl2nvc_c.html
+[psv2pl_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/psv2pl_c.html
 [pxform_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pxform_c.html
 [pckcov_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pckcov_c.html
 [pckfrm_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pckfrm_c.html
+[pgrrec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pgrrec_c.html
 [pxfrm2_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pxfrm2_c.html
+[q2m_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/q2m_c.html
+[raxisa_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/raxisa_c.html
+[saelgv_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/saelgv_c.html
 [scdecd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/scdecd_c.html
 [sce2c_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sce2c_c.html
 [sce2s_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sce2s_c.html
@@ -137,16 +259,24 @@ CSPICE | **rust-spice** | Description
 [scs2e_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/scs2e_c.html
 [sct2e_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sct2e_c.html
 [sincpt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sincpt_c.html
+[sphrec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sphrec_c.html
+[spkacs_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkacs_c.html
+[spkapo_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkapo_c.html
 [spkcls_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkcls_c.html
 [spkcpo_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkcpo_c.html
 [spkcov_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkcov_c.html
 [spkcpt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkcpt_c.html
 [spkcvo_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkcvo_c.html
 [spkcvt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkcvt_c.html
+[spkez_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkez_c.html
+[spkezp_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkezp_c.html
 [spkezr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkezr_c.html
+[spkgeo_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkgeo_c.html
+[spkgps_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkgps_c.html
 [spkobj_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkobj_c.html
 [spkopn_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkopn_c.html
 [spkpos_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkpos_c.html
+[spkssb_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkssb_c.html
 [spkw09_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkw09_c.html
 [srfc2s_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/srfc2s_c.html
 [srfcss_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/srfcss_c.html
@@ -155,17 +285,27 @@ CSPICE | **rust-spice** | Description
 [srfscc_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/srfscc_c.html
 [str2et_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/str2et_c.html
 [subpnt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/subpnt_c.html
+[subslr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/subslr_c.html
+[surfnm_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/surfnm_c.html
 [surfpt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/surfpt_c.html
 [sxform_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sxform_c.html
 [radrec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/radrec_c.html
+[recazl_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/recazl_c.html
+[reccyl_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/reccyl_c.html
+[recgeo_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/recgeo_c.html
+[reclat_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/reclat_c.html
 [recrad_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/recrad_c.html
 [recpgr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/recpgr_c.html
+[recsph_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/recsph_c.html
+[termpt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/termpt_c.html
 [timout_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/timout_c.html
+[tpictr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/tpictr_c.html
 [unitim_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/unitim_c.html
 [unload_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/unload_c.html
 [vcrss_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/vcrss_c.html
 [vdot_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/vdot_c.html
 [vsep_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/vsep_c.html
+[xfmsta_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/xfmsta_c.html
 [xpose_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/xpose_c.html
 */
 
@@ -173,15 +313,94 @@ CSPICE | **rust-spice** | Description
 #[cfg_attr(docsrs, doc(cfg(feature = "lock")))]
 pub mod lock;
 
+/**
+Implement the conversions from strings of a type implementing [`FromStr`][std::str::FromStr], so
+that the functions taking the type keep accepting strings. An invalid string makes them panic with
+the message of the parsing error.
+*/
+macro_rules! impl_from_str {
+    ($t:ty) => {
+        impl From<&str> for $t {
+            fn from(s: &str) -> Self {
+                s.parse().unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl From<&String> for $t {
+            fn from(s: &String) -> Self {
+                Self::from(s.as_str())
+            }
+        }
+
+        impl From<String> for $t {
+            fn from(s: String) -> Self {
+                Self::from(s.as_str())
+            }
+        }
+    };
+}
+
+mod aberration;
+pub mod algebra;
+mod body;
+mod bvh;
+mod coordinates;
+mod dsk;
+mod ellipse;
+mod epoch;
+mod error;
+mod illumination;
+mod leapseconds;
+mod limb;
+mod mesh;
+mod method;
 pub mod neat;
+mod plane;
+mod properties;
 pub mod raw;
+mod render;
+mod surface;
+mod time_format;
 
-pub use self::neat::{bodc2n, dskp02, dskv02, kdata, timout};
+pub use self::aberration::Aberration;
+pub use self::body::Body;
+pub use self::bvh::{Bvh, RayHit};
+pub use self::coordinates::{
+    AzEl, Cylindrical, Geodetic, Latitudinal, Planetographic, RaDec, Spherical,
+};
+pub use self::dsk::{
+    CoordinateSystem, DataClass, Dsk02Keyword, DskDescriptor, DskSegments, DskWriter,
+};
+pub use self::ellipse::Ellipse;
+pub use self::epoch::Epoch;
+pub(crate) use self::error::catch;
+pub use self::error::Error;
+pub use self::illumination::{Illumination, SubPoint};
+pub use self::leapseconds::LeapSeconds;
+pub use self::limb::{CorrectionLocus, CurveType, Cut, Cuts, Shadow};
+pub(crate) use self::mesh::validate_plates;
+pub use self::mesh::{MeshFormat, PlateModel};
+pub use self::method::{ShapeModel, SubPointMethod};
+pub use self::plane::Plane;
+pub use self::properties::TriaxialEllipsoid;
+pub use self::render::{ImageFormat, PixelMap, Reflectance, Renderer, Rendering};
+pub use self::surface::Surface;
+pub use self::time_format::{DateStyle, MonthStyle, Rounding, TimeFormat, TimeSystem, UtcFormat};
+
+pub use self::neat::{
+    bodc2n, dskd02, dski02, dskp02, dskv02, et2utc, frmnam, illumg, ilumin, kdata, limbpt, srfc2s,
+    srfcss, subslr, termpt, timout, tpictr,
+};
 pub use self::raw::{
-    bodfnd, bodn2c, bodvrd, dascls, dasopr, deltet, dlabfs, dskgd, dskn02, dskobj, dskx02, dskz02,
-    furnsh, gdpool, georec, getfov, illumf, kclear, ktotal, latrec, mxv, occult, pxform, pxfrm2,
-    radrec, recpgr, recrad, sincpt, spkcls, spkezr, spkopn, spkpos, spkw09, str2et, subpnt, surfpt,
-    unitim, unload, vcrss, vdot, vsep, xpose, Cell, DLADSC, DSKDSC,
+    axisar, azlrec, boddef, bodfnd, bodn2c, bodvcd, bodvrd, cylrec, dascls, dasopr, dcyldr, deltet,
+    dgeodr, dlabbs, dlabfs, dlafns, dlafps, dlatdr, dpgrdr, drdcyl, drdgeo, drdlat, drdpgr, drdsph,
+    dskb02, dskcls, dskgd, dskmi2, dskn02, dskobj, dskopn, dskrb2, dskw02, dskx02, dskxsi, dskxv,
+    dskz02, dsphdr, dtpool, edlimb, eul2m, furnsh, gdpool, georec, getfov, illumf, inedpl, inrypl,
+    kclear, ktotal, latrec, latsrf, m2eul, m2q, mxv, nearpt, npedln, npelpt, nvc2pl, nvp2pl,
+    occult, pgrrec, phaseq, pl2nvc, psv2pl, pxform, pxfrm2, q2m, radrec, raxisa, recazl, reccyl,
+    recgeo, reclat, recpgr, recrad, recsph, saelgv, sincpt, sphrec, spkacs, spkapo, spkcls, spkez,
+    spkezp, spkezr, spkgeo, spkgps, spkopn, spkpos, spkssb, spkw09, srfnrm, srfs2c, srfscc, str2et,
+    subpnt, surfnm, surfpt, unitim, unload, vcrss, vdot, vsep, xfmsta, xpose, Cell, DLADSC, DSKDSC,
 };
 
 /**
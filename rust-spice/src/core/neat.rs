@@ -13,8 +13,13 @@ an idiomatic usage. The improvements consists in functions:
 + which outputs string that be allocated from default length sometimes
 */
 
+use crate::c::SPICE_DSK_DSCSIZ;
+use crate::core::catch;
 use crate::raw;
-use crate::MAX_LEN_OUT;
+use crate::{
+    Aberration, CorrectionLocus, CurveType, Cut, Cuts, Dsk02Keyword, Error, Illumination, Shadow,
+    ShapeModel, SubPoint, SubPointMethod, UtcFormat, MAX_LEN_OUT,
+};
 #[cfg(any(feature = "lock", doc))]
 use {crate::SpiceLock, spice_derive::impl_for};
 
@@ -28,6 +33,19 @@ pub fn bodc2n(code: i32) -> (String, bool) {
     raw::bodc2n(code, MAX_LEN_OUT as i32)
 }
 
+/**
+Convert an input time from ephemeris seconds past J2000 to Calendar, Day-of-Year, or Julian Date
+format, UTC.
+
+The format is either a [`UtcFormat`] or one of the strings understood by CSPICE.
+
+See [`raw::et2utc`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn et2utc(et: f64, format: impl Into<UtcFormat>, prec: i32) -> String {
+    raw::et2utc(et, format, prec, MAX_LEN_OUT)
+}
+
 /**
 This routine converts an input epoch represented in TDB seconds past the TDB epoch of J2000 to a
 character string formatted to the specifications of a user's format picture.
@@ -39,6 +57,19 @@ pub fn timout(et: f64, pictur: &str) -> String {
     raw::timout(et, pictur, pictur.len())
 }
 
+/**
+Create a time format picture suitable for use by [`timout`] from a given sample time string.
+
+See [`raw::tpictr`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn tpictr(sample: &str) -> Result<String, Error> {
+    match raw::tpictr(sample, MAX_LEN_OUT, MAX_LEN_OUT) {
+        (pictur, true, _) => Ok(pictur),
+        (_, false, error) => Err(Error::InvalidTimeSample(error)),
+    }
+}
+
 /**
 Fetch triangular plates from a type 2 DSK segment.
 
@@ -61,6 +92,70 @@ pub fn dskv02(handle: i32, dladsc: raw::DLADSC) -> Vec<[f64; 3]> {
     raw::dskv02(handle, dladsc, 1, nv as _)
 }
 
+/**
+Fetch a double precision item of a type 2 DSK segment, such as its vertex bounds or its voxel
+origin.
+
+Integer items are rejected before reaching CSPICE, whose errors are returned as well.
+
+See [`raw::dskd02`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn dskd02(handle: i32, dladsc: raw::DLADSC, item: Dsk02Keyword) -> Result<Vec<f64>, Error> {
+    if !item.is_double() {
+        return Err(Error::InvalidItem(format!("{:?} holds integers", item)));
+    }
+    catch(|| {
+        let room = dsk02_size(handle, dladsc, item);
+        raw::dskd02(handle, dladsc, item.code(), 1, room)
+    })
+}
+
+/**
+Fetch an integer item of a type 2 DSK segment, such as its voxel-plate pointers or its
+voxel-plate list.
+
+Double precision items are rejected before reaching CSPICE, whose errors are returned as well.
+
+See [`raw::dski02`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn dski02(handle: i32, dladsc: raw::DLADSC, item: Dsk02Keyword) -> Result<Vec<i32>, Error> {
+    if item.is_double() {
+        return Err(Error::InvalidItem(format!(
+            "{:?} holds double precision numbers",
+            item
+        )));
+    }
+    catch(|| {
+        let room = dsk02_size(handle, dladsc, item);
+        raw::dski02(handle, dladsc, item.code(), 1, room)
+    })
+}
+
+/**
+Number of values of an item of a type 2 DSK segment, computed from its bookkeeping data.
+
+The bookkeeping data are zero if [`raw::dskb02`] failed, which gives an empty item.
+*/
+fn dsk02_size(handle: i32, dladsc: raw::DLADSC, item: Dsk02Keyword) -> usize {
+    let (nv, np, nvxtot, _, _, _, _, cgscal, vtxnpl, voxnpt, voxnpl) = raw::dskb02(handle, dladsc);
+    (match item {
+        Dsk02Keyword::VoxelGridExtent | Dsk02Keyword::VoxelOrigin => 3,
+        Dsk02Keyword::Plates => 3 * np,
+        Dsk02Keyword::VoxelPointers => voxnpt,
+        Dsk02Keyword::VoxelPlateList => voxnpl,
+        Dsk02Keyword::VertexPointers => nv,
+        Dsk02Keyword::VertexPlateList => vtxnpl,
+        Dsk02Keyword::CoarseGridPointers if cgscal > 0 => nvxtot / cgscal.pow(3),
+        Dsk02Keyword::CoarseGridPointers => 0,
+        Dsk02Keyword::Descriptor => SPICE_DSK_DSCSIZ as i32,
+        Dsk02Keyword::VertexBounds => 6,
+        Dsk02Keyword::Vertices => 3 * nv,
+        _ => 1,
+    }) as usize
+}
+
 /**
 Fetch vertices from a type 2 DSK segment.
 
@@ -76,3 +171,168 @@ pub fn kdata(which: i32, kind: &str) -> (String, String, String, i32, bool) {
         MAX_LEN_OUT as i32,
     )
 }
+
+/**
+Translate a surface ID code, together with a body ID code, to the corresponding surface name.
+
+See [`raw::srfc2s`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn srfc2s(code: i32, bodyid: i32) -> (String, bool) {
+    raw::srfc2s(code, bodyid, MAX_LEN_OUT as i32)
+}
+
+/**
+Translate a surface ID code, together with a body string, to the corresponding surface name.
+
+See [`raw::srfcss`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn srfcss(code: i32, bodstr: &str) -> (String, bool) {
+    raw::srfcss(code, bodstr, MAX_LEN_OUT as i32)
+}
+
+/**
+Retrieve the name of a reference frame associated with a SPICE ID code, blank if the code is not
+recognized.
+
+See [`raw::frmnam`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn frmnam(frcode: i32) -> String {
+    raw::frmnam(frcode, MAX_LEN_OUT as i32)
+}
+
+/**
+Find limb points on a target body, in the given cuts.
+
+See [`raw::limbpt`] for the raw interface.
+*/
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn limbpt(
+    curve: CurveType,
+    shape: impl Into<ShapeModel>,
+    target: &str,
+    et: f64,
+    fixref: &str,
+    abcorr: impl Into<Aberration>,
+    corloc: CorrectionLocus,
+    obsrvr: &str,
+    cuts: &Cuts,
+) -> Vec<Cut> {
+    let (npts, points, epochs, tangts) = raw::limbpt(
+        &format!("{}/{}", curve.as_str(), shape.into()),
+        target,
+        et,
+        fixref,
+        abcorr,
+        corloc.as_limb_str(),
+        obsrvr,
+        cuts.refvec,
+        cuts.rolstp,
+        cuts.ncuts,
+        cuts.schstp,
+        cuts.soltol,
+        cuts.maxn,
+    );
+    Cut::split(&npts, &points, &epochs, &tangts)
+}
+
+/**
+Find terminator points on a target body, in the given cuts.
+
+See [`raw::termpt`] for the raw interface.
+*/
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn termpt(
+    shadow: Shadow,
+    curve: CurveType,
+    shape: impl Into<ShapeModel>,
+    ilusrc: &str,
+    target: &str,
+    et: f64,
+    fixref: &str,
+    abcorr: impl Into<Aberration>,
+    corloc: CorrectionLocus,
+    obsrvr: &str,
+    cuts: &Cuts,
+) -> Vec<Cut> {
+    let (npts, points, epochs, tangts) = raw::termpt(
+        &format!("{}/{}/{}", shadow.as_str(), curve.as_str(), shape.into()),
+        ilusrc,
+        target,
+        et,
+        fixref,
+        abcorr,
+        corloc.as_terminator_str(),
+        obsrvr,
+        cuts.refvec,
+        cuts.rolstp,
+        cuts.ncuts,
+        cuts.schstp,
+        cuts.soltol,
+        cuts.maxn,
+    );
+    Cut::split(&npts, &points, &epochs, &tangts)
+}
+
+/**
+Find the illumination angles at a surface point of a target body, the Sun being the source of
+illumination, and whether the point is visible and lit.
+
+The angles are the ones of [`raw::ilumin`], found with [`raw::illumf`] which also tells whether
+the point is visible and lit.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn ilumin(
+    method: impl Into<ShapeModel>,
+    target: &str,
+    et: f64,
+    fixref: &str,
+    abcorr: impl Into<Aberration>,
+    obsrvr: &str,
+    spoint: [f64; 3],
+) -> Illumination {
+    raw::illumf(method, target, "SUN", et, fixref, abcorr, obsrvr, spoint).into()
+}
+
+/**
+Find the illumination angles at a surface point of a target body, the source of illumination
+being a given ephemeris object, and whether the point is visible and lit.
+
+The angles are the ones of [`raw::illumg`], found with [`raw::illumf`] which also tells whether
+the point is visible and lit.
+*/
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn illumg(
+    method: impl Into<ShapeModel>,
+    target: &str,
+    ilusrc: &str,
+    et: f64,
+    fixref: &str,
+    abcorr: impl Into<Aberration>,
+    obsrvr: &str,
+    spoint: [f64; 3],
+) -> Illumination {
+    raw::illumf(method, target, ilusrc, et, fixref, abcorr, obsrvr, spoint).into()
+}
+
+/**
+Compute the sub-solar point on a target body.
+
+See [`raw::subslr`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn subslr(
+    method: impl Into<SubPointMethod>,
+    target: &str,
+    et: f64,
+    fixref: &str,
+    abcorr: impl Into<Aberration>,
+    obsrvr: &str,
+) -> SubPoint {
+    raw::subslr(method, target, et, fixref, abcorr, obsrvr).into()
+}
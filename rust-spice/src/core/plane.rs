@@ -0,0 +1,37 @@
+/*!
+Planes of SPICE, see the [C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/planes.html).
+*/
+
+use crate::c::SpicePlane;
+
+/**
+Plane of the points `x` such that `normal · x = constant`.
+
+The planes built by CSPICE, with [`raw::nvc2pl`][crate::raw::nvc2pl] for instance, have a unit
+normal and a non-negative constant, which is then the distance of the plane from the origin.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Plane {
+    /// Normal vector.
+    pub normal: [f64; 3],
+    /// Constant of the equation of the plane.
+    pub constant: f64,
+}
+
+impl From<SpicePlane> for Plane {
+    fn from(plane: SpicePlane) -> Self {
+        Self {
+            normal: plane.normal,
+            constant: plane.constant,
+        }
+    }
+}
+
+impl From<Plane> for SpicePlane {
+    fn from(plane: Plane) -> Self {
+        Self {
+            normal: plane.normal,
+            constant: plane.constant,
+        }
+    }
+}
@@ -0,0 +1,262 @@
+/*!
+Mass properties and geometry statistics of plate models.
+*/
+
+use crate::math::{det, vadd, vcrss, vlcom, vminus, vnorm, vscl, vsep, vsub, Matrix, Vector};
+use crate::na::{Matrix3, SMatrix, SVector, SymmetricEigen, Vector3};
+use crate::PlateModel;
+use std::f64::consts::PI;
+
+/**
+Triaxial ellipsoid derived from a plate model, by [`PlateModel::equivalent_ellipsoid`] or
+[`PlateModel::best_fit_ellipsoid`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriaxialEllipsoid {
+    /// Center.
+    pub center: Vector,
+    /// Semi-axes, from the largest to the smallest.
+    pub radii: Vector,
+    /// Unit principal axes along the semi-axes, as rows.
+    pub axes: Matrix,
+}
+
+impl PlateModel {
+    /// Area of each plate.
+    pub fn areas(&self) -> Vec<f64> {
+        self.plates
+            .iter()
+            .map(|plate| {
+                let [a, b, c] = self.corners(plate);
+                0.5 * vnorm(&vcrss(&vsub(&b, &a), &vsub(&c, &a)))
+            })
+            .collect()
+    }
+
+    /// Center of each plate, the mean of its vertices.
+    pub fn centers(&self) -> Vec<Vector> {
+        self.plates
+            .iter()
+            .map(|plate| {
+                let [a, b, c] = self.corners(plate);
+                vscl(1.0 / 3.0, &vadd(&vadd(&a, &b), &c))
+            })
+            .collect()
+    }
+
+    /// Total area of the plates.
+    pub fn area(&self) -> f64 {
+        self.areas().iter().sum()
+    }
+
+    /**
+    Volume enclosed by the plates, positive when their normals point outwards.
+
+    The volume is the sum of the signed volumes of the tetrahedra joining the origin to each plate,
+    so that the model must be closed.
+    */
+    pub fn volume(&self) -> f64 {
+        self.plates
+            .iter()
+            .map(|plate| det(&self.corners(plate)) / 6.0)
+            .sum()
+    }
+
+    /// Center of figure: the centroid of the enclosed volume, the center of mass under constant
+    /// density.
+    pub fn center_of_figure(&self) -> Vector {
+        let (volume, first, _) = self.moments();
+        vscl(1.0 / volume, &first)
+    }
+
+    /**
+    Inertia tensor about the center of figure, per unit density.
+
+    Multiply it by the density, in kg/km³, to get the tensor in kg km². The products of inertia are
+    the off-diagonal elements, with their minus sign.
+    */
+    pub fn inertia(&self) -> Matrix {
+        let (volume, first, second) = self.moments();
+        let center = vscl(1.0 / volume, &first);
+
+        // Second moments about the center of figure, then the tensor from them.
+        let mut covariance = second;
+        for (i, row) in covariance.iter_mut().enumerate() {
+            for (j, element) in row.iter_mut().enumerate() {
+                *element -= volume * center[i] * center[j];
+            }
+        }
+        let trace = covariance[0][0] + covariance[1][1] + covariance[2][2];
+        let mut inertia = [[0.0; 3]; 3];
+        for (i, row) in inertia.iter_mut().enumerate() {
+            for (j, element) in row.iter_mut().enumerate() {
+                *element = -covariance[i][j];
+            }
+            row[i] += trace;
+        }
+        inertia
+    }
+
+    /// Radius of the sphere of the same volume.
+    pub fn equivalent_radius(&self) -> f64 {
+        (3.0 * self.volume() / (4.0 * PI)).cbrt()
+    }
+
+    /**
+    Dynamically equivalent equal volume ellipsoid: the ellipsoid centered on the center of
+    figure, with the same principal axes and ratios of principal moments of inertia as the model,
+    scaled to its volume. This is not a fit of the surface, see
+    [`PlateModel::best_fit_ellipsoid`].
+
+    The semi-axes `a ≥ b ≥ c` of an ellipsoid of volume `V` and unit density give its principal
+    moments `V (b² + c²) / 5`, `V (a² + c²) / 5` and `V (a² + b²) / 5`. They are solved for from
+    the principal moments of the model, then scaled so that the ellipsoid has the volume of the
+    model. There is none if the model has no volume, or is so flat that a semi-axis vanishes.
+    */
+    pub fn equivalent_ellipsoid(&self) -> Option<TriaxialEllipsoid> {
+        let volume = self.volume();
+        if volume <= 0.0 {
+            return None;
+        }
+        let inertia = self.inertia();
+        let eigen = SymmetricEigen::new(Matrix3::from_fn(|i, j| inertia[i][j]));
+
+        // The smallest moment is about the largest axis.
+        let (order, axes) = principal_axes(&eigen);
+        let [a, b, c] = order.map(|k| eigen.eigenvalues[k]);
+        let squares = [b + c - a, a + c - b, a + b - c].map(|s| (2.5 * s / volume).max(0.0));
+        let radii = squares.map(f64::sqrt);
+
+        let product = radii[0] * radii[1] * radii[2];
+        if product <= 0.0 {
+            return None;
+        }
+        let scale = (volume / (4.0 / 3.0 * PI * product)).cbrt();
+
+        Some(TriaxialEllipsoid {
+            center: self.center_of_figure(),
+            radii: radii.map(|r| scale * r),
+            axes,
+        })
+    }
+
+    /**
+    Triaxial ellipsoid fitted to the vertices by linear least squares.
+
+    The fitted quadric `xᵀ M x + 2 gᵀ x = 1` is centered on `c = -M⁻¹ g`, where it reads
+    `yᵀ M y = 1 + cᵀ M c`, whose eigenvalues give the semi-axes. The vertices are taken relative to
+    their mean, for the conditioning of the least squares. There is none if there are less than 9
+    vertices, or if they are not spread around an ellipsoid, so that the quadric is not one.
+    */
+    pub fn best_fit_ellipsoid(&self) -> Option<TriaxialEllipsoid> {
+        if self.vertices.len() < 9 {
+            return None;
+        }
+        let mean = vscl(
+            1.0 / self.vertices.len() as f64,
+            &self.vertices.iter().fold([0.0; 3], |sum, v| vadd(&sum, v)),
+        );
+
+        // Normal equations of the coefficients of the quadric.
+        let mut normal = SMatrix::<f64, 9, 9>::zeros();
+        let mut rhs = SVector::<f64, 9>::zeros();
+        for vertex in &self.vertices {
+            let [x, y, z] = vsub(vertex, &mean);
+            let terms = SVector::<f64, 9>::from([
+                x * x,
+                y * y,
+                z * z,
+                2.0 * x * y,
+                2.0 * x * z,
+                2.0 * y * z,
+                2.0 * x,
+                2.0 * y,
+                2.0 * z,
+            ]);
+            normal += terms * terms.transpose();
+            rhs += terms;
+        }
+        let p = normal.cholesky()?.solve(&rhs);
+
+        let quadric = Matrix3::new(p[0], p[3], p[4], p[3], p[1], p[5], p[4], p[5], p[2]);
+        let center = -(quadric.try_inverse()? * Vector3::new(p[6], p[7], p[8]));
+        let level = 1.0 + center.dot(&(quadric * center));
+        let eigen = SymmetricEigen::new(quadric / level);
+        if eigen
+            .eigenvalues
+            .iter()
+            .any(|&l| l <= 0.0 || !l.is_finite())
+        {
+            return None;
+        }
+
+        // The smallest eigenvalue is along the largest axis.
+        let (order, axes) = principal_axes(&eigen);
+        Some(TriaxialEllipsoid {
+            center: vadd(&mean, &[center[0], center[1], center[2]]),
+            radii: order.map(|k| 1.0 / eigen.eigenvalues[k].sqrt()),
+            axes,
+        })
+    }
+
+    /**
+    Slope of each plate: the angle between its normal and the direction opposite to the local
+    gravity, in radians.
+
+    The gravity is given by a function of the center of the plate, such as `|_| g` for a uniform
+    field or `|p| spice::math::vminus(p)` for a point mass at the origin. A plate facing the
+    gravity has a slope of π.
+    */
+    pub fn slopes<F: Fn(&Vector) -> Vector>(&self, gravity: F) -> Vec<f64> {
+        self.normals()
+            .iter()
+            .zip(self.centers())
+            .map(|(normal, center)| vsep(normal, &vminus(&gravity(&center))))
+            .collect()
+    }
+
+    /**
+    Volume, first moments and second moments of the enclosed volume, per unit density.
+
+    Each plate spans a tetrahedron with the origin, whose moments are the ones of its vertices:
+    `∫ x dV = V (a + b + c) / 4` and `∫ x xᵀ dV = V (Σ v vᵀ + s sᵀ) / 20`, where `s = a + b + c`.
+    */
+    fn moments(&self) -> (f64, Vector, Matrix) {
+        let mut volume = 0.0;
+        let mut first = [0.0; 3];
+        let mut second = [[0.0; 3]; 3];
+
+        for plate in &self.plates {
+            let corners = self.corners(plate);
+            let v = det(&corners) / 6.0;
+            let [a, b, c] = corners;
+            let s = vadd(&vadd(&a, &b), &c);
+
+            volume += v;
+            first = vlcom(1.0, &first, v / 4.0, &s);
+            for (i, row) in second.iter_mut().enumerate() {
+                for (j, element) in row.iter_mut().enumerate() {
+                    let outer = a[i] * a[j] + b[i] * b[j] + c[i] * c[j] + s[i] * s[j];
+                    *element += v / 20.0 * outer;
+                }
+            }
+        }
+
+        (volume, first, second)
+    }
+}
+
+/// Indices of the eigenvalues from the smallest to the largest, and the unit eigenvectors in the
+/// same order as the rows of a right-handed frame.
+fn principal_axes(eigen: &SymmetricEigen<f64, crate::na::U3>) -> ([usize; 3], Matrix) {
+    let mut order = [0, 1, 2];
+    order.sort_by(|&a, &b| eigen.eigenvalues[a].total_cmp(&eigen.eigenvalues[b]));
+    let mut axes = order.map(|k| {
+        let axis = eigen.eigenvectors.column(k);
+        [axis[0], axis[1], axis[2]]
+    });
+    if det(&axes) < 0.0 {
+        axes[2] = vminus(&axes[2]);
+    }
+    (order, axes)
+}
@@ -3,12 +3,17 @@ A Rust idiomatic CSPICE wrapper built with [procedural macros][`spice_derive`].
 */
 
 use crate::c::{
-    SpiceBoolean, SpiceCell, SpiceChar, SpiceDLADescr, SpiceDSKDescr, SpiceDouble, SpiceInt,
-    _SpiceDataType_SPICE_BOOL, _SpiceDataType_SPICE_CHR, _SpiceDataType_SPICE_DP,
-    _SpiceDataType_SPICE_INT, _SpiceDataType_SPICE_TIME, SPICE_CELL_CTRLSZ,
+    SpiceBoolean, SpiceCell, SpiceChar, SpiceDLADescr, SpiceDSKDescr, SpiceDouble, SpiceEllipse,
+    SpiceInt, SpicePlane, _SpiceDataType_SPICE_BOOL, _SpiceDataType_SPICE_CHR,
+    _SpiceDataType_SPICE_DP, _SpiceDataType_SPICE_INT, _SpiceDataType_SPICE_TIME, SPICE_CELL_CTRLSZ,
+    SPICE_DSK02_SPADSZ, SPICE_DSKXSI_DCSIZE, SPICE_DSKXSI_ICSIZE, SPICE_DSK_NSYPAR,
+};
+use crate::{
+    cstr, fcstr, malloc, mallocstr, Aberration, Ellipse, Plane, ShapeModel, SubPointMethod,
+    UtcFormat,
 };
-use crate::{cstr, fcstr, malloc, mallocstr};
 use spice_derive::{cspice_proc, return_output};
+use std::ffi::CString;
 use std::ops::{Deref, DerefMut};
 
 #[cfg(any(feature = "lock", doc))]
@@ -153,6 +158,27 @@ impl DerefMut for Cell {
     }
 }
 
+cspice_proc! {
+    /**
+    Construct the rotation matrix that rotates vectors by `angle` radians about `axis`.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn axisar(axis: [f64; 3], angle: f64) -> [[f64; 3]; 3] {}
+}
+
+/**
+Convert from range, azimuth and elevation of a point to rectangular coordinates.
+
+`azccw` tells whether the azimuth increases counterclockwise about the positive Z axis, and
+`elplsz` whether the elevation increases towards it.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn azlrec(range: f64, az: f64, el: f64, azccw: bool, elplsz: bool) -> [f64; 3] {
+    let mut rectan = [0.0; 3];
+    unsafe { crate::c::azlrec_c(range, az, el, azccw as _, elplsz as _, rectan.as_mut_ptr()) };
+    rectan
+}
+
 cspice_proc! {
     /**
     Translate the SPICE integer code of a body into a common name for that body.
@@ -162,6 +188,14 @@ cspice_proc! {
     pub fn bodc2n(code: i32, lenout: i32) -> (String, bool) {}
 }
 
+cspice_proc! {
+    /**
+    Define a body name/ID code pair for later translation via [`bodn2c`] or [`bodc2n`].
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn boddef(name: &str, code: i32) {}
+}
+
 cspice_proc! {
     /**
     Determine whether values exist for some item for any body in the kernel pool.
@@ -193,6 +227,36 @@ pub fn bodvrd(bodynm: &str, item: &str, maxn: usize) -> Vec<f64> {
     values
 }
 
+/**
+Fetch from the kernel pool the double precision values of an item associated with a body,
+where the body is given by its NAIF ID.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn bodvcd(bodyid: i32, item: &str, maxn: usize) -> Vec<f64> {
+    let item = CString::new(item).unwrap();
+    let mut dim = 0;
+    let mut values = vec![0.0; maxn];
+    unsafe {
+        crate::c::bodvcd_c(
+            bodyid,
+            item.as_ptr() as *mut _,
+            maxn as _,
+            &mut dim,
+            values.as_mut_ptr(),
+        )
+    };
+    values.truncate(dim as _);
+    values
+}
+
+cspice_proc! {
+    /**
+    Convert from cylindrical to rectangular coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn cylrec(r: f64, clon: f64, z: f64) -> [f64; 3] {}
+}
+
 cspice_proc! {
     /**
     close a das file.
@@ -209,6 +273,14 @@ cspice_proc! {
     pub fn dasopr(fname: &str) -> i32 {}
 }
 
+cspice_proc! {
+    /**
+    Compute the Jacobian of the transformation from rectangular to cylindrical coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dcyldr(x: f64, y: f64, z: f64) -> [[f64; 3]; 3] {}
+}
+
 /**
 Return the value of Delta ET (ET-UTC) for an input epoch.
 */
@@ -221,6 +293,22 @@ pub fn deltet(epoch: f64, eptype: &str) -> f64 {
     delta
 }
 
+cspice_proc! {
+    /**
+    Compute the Jacobian of the transformation from rectangular to geodetic coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dgeodr(x: f64, y: f64, z: f64, re: f64, f: f64) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Begin a backward segment search in a DLA file.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dlabbs(handle: i32) -> (DLADSC, bool) {}
+}
+
 cspice_proc! {
     /**
     Begin a forward segment search in a DLA file.
@@ -229,6 +317,182 @@ cspice_proc! {
     pub fn dlabfs(handle: i32) -> (DLADSC, bool) {}
 }
 
+cspice_proc! {
+    /**
+    Find the segment following a specified segment in a DLA file.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dlafns(handle: i32, dladsc: DLADSC) -> (DLADSC, bool) {}
+}
+
+cspice_proc! {
+    /**
+    Find the segment preceding a specified segment in a DLA file.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dlafps(handle: i32, dladsc: DLADSC) -> (DLADSC, bool) {}
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian of the transformation from rectangular to latitudinal coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dlatdr(x: f64, y: f64, z: f64) -> [[f64; 3]; 3] {}
+}
+
+/**
+Compute the Jacobian of the transformation from rectangular to planetographic coordinates.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn dpgrdr(body: &str, x: f64, y: f64, z: f64, re: f64, f: f64) -> [[f64; 3]; 3] {
+    let body = CString::new(body).unwrap();
+    let mut jacobi = [[0.0; 3]; 3];
+    unsafe { crate::c::dpgrdr_c(body.as_ptr() as *mut _, x, y, z, re, f, jacobi.as_mut_ptr()) };
+    jacobi
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian of the transformation from cylindrical to rectangular coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn drdcyl(r: f64, clon: f64, z: f64) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian of the transformation from geodetic to rectangular coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn drdgeo(lon: f64, lat: f64, alt: f64, re: f64, f: f64) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian of the transformation from latitudinal to rectangular coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn drdlat(r: f64, lon: f64, lat: f64) -> [[f64; 3]; 3] {}
+}
+
+/**
+Compute the Jacobian of the transformation from planetographic to rectangular coordinates.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn drdpgr(body: &str, lon: f64, lat: f64, alt: f64, re: f64, f: f64) -> [[f64; 3]; 3] {
+    let body = CString::new(body).unwrap();
+    let mut jacobi = [[0.0; 3]; 3];
+    unsafe {
+        crate::c::drdpgr_c(
+            body.as_ptr() as *mut _,
+            lon,
+            lat,
+            alt,
+            re,
+            f,
+            jacobi.as_mut_ptr(),
+        )
+    };
+    jacobi
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian of the transformation from spherical to rectangular coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn drdsph(r: f64, colat: f64, lon: f64) -> [[f64; 3]; 3] {}
+}
+
+/**
+Return bookkeeping data from a type 2 DSK segment: the counts of vertices, plates and voxels, the
+bounds of the vertices, the size and origin of the voxels, the extent of the voxel grid, the scale
+of the coarse voxels, and the sizes of the vertex-plate list, of the voxel-plate pointer array and
+of the voxel-plate list.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+#[allow(clippy::type_complexity)]
+pub fn dskb02(
+    handle: i32,
+    mut dladsc: DLADSC,
+) -> (
+    i32,
+    i32,
+    i32,
+    [[f64; 2]; 3],
+    f64,
+    [f64; 3],
+    [i32; 3],
+    i32,
+    i32,
+    i32,
+    i32,
+) {
+    let (mut nv, mut np, mut nvxtot) = (0, 0, 0);
+    let mut vtxbds = [[0.0; 2]; 3];
+    let mut voxsiz = 0.0;
+    let mut voxori = [0.0; 3];
+    let mut vgrext = [0; 3];
+    let (mut cgscal, mut vtxnpl, mut voxnpt, mut voxnpl) = (0, 0, 0, 0);
+
+    unsafe {
+        crate::c::dskb02_c(
+            handle,
+            &mut dladsc,
+            &mut nv,
+            &mut np,
+            &mut nvxtot,
+            vtxbds.as_mut_ptr(),
+            &mut voxsiz,
+            voxori.as_mut_ptr(),
+            vgrext.as_mut_ptr(),
+            &mut cgscal,
+            &mut vtxnpl,
+            &mut voxnpt,
+            &mut voxnpl,
+        );
+    }
+
+    (
+        nv, np, nvxtot, vtxbds, voxsiz, voxori, vgrext, cgscal, vtxnpl, voxnpt, voxnpl,
+    )
+}
+
+/**
+Fetch double precision data from a type 2 DSK segment, the item being one of the d.p.
+[`Dsk02Keyword`][crate::Dsk02Keyword] codes.
+
+This function has a [neat version][crate::neat::dskd02].
+*/
+pub fn dskd02(handle: i32, mut dladsc: DLADSC, item: i32, start: usize, room: usize) -> Vec<f64> {
+    let mut n = 0;
+    let mut values = vec![0.0; room];
+
+    unsafe {
+        crate::c::dskd02_c(
+            handle,
+            &mut dladsc,
+            item,
+            start as _,
+            room as _,
+            &mut n,
+            values.as_mut_ptr(),
+        );
+    }
+
+    values.truncate(n as _);
+    values
+}
+
+/**
+Close a DSK file, optionally compressing its segments to reduce its size.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn dskcls(handle: i32, optmiz: bool) {
+    unsafe { crate::c::dskcls_c(handle, optmiz as _) };
+}
+
 cspice_proc! {
     /**
     Return the DSK descriptor from a DSK segment identified  by a DAS handle and DLA descriptor.
@@ -237,6 +501,95 @@ cspice_proc! {
     pub fn dskgd(handle: i32, dladsc: DLADSC) -> DSKDSC {}
 }
 
+/**
+Fetch integer data from a type 2 DSK segment, the item being one of the integer
+[`Dsk02Keyword`][crate::Dsk02Keyword] codes.
+
+This function has a [neat version][crate::neat::dski02].
+*/
+pub fn dski02(handle: i32, mut dladsc: DLADSC, item: i32, start: usize, room: usize) -> Vec<i32> {
+    let mut n = 0;
+    let mut values = vec![0; room];
+
+    unsafe {
+        crate::c::dski02_c(
+            handle,
+            &mut dladsc,
+            item,
+            start as _,
+            room as _,
+            &mut n,
+            values.as_mut_ptr(),
+        );
+    }
+
+    values.truncate(n as _);
+    values
+}
+
+/**
+Make spatial index for a DSK type 2 segment.
+
+The workspace of `worksz` pairs and the integer spatial index of `spxisz` elements are allocated
+here. The double precision and integer components of the spatial index are returned, ready for
+[`dskw02`].
+*/
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn dskmi2(
+    vrtces: &[[f64; 3]],
+    plates: &[[i32; 3]],
+    finscl: f64,
+    corscl: i32,
+    worksz: usize,
+    voxpsz: i32,
+    voxlsz: i32,
+    makvtl: bool,
+    spxisz: usize,
+) -> ([f64; SPICE_DSK02_SPADSZ as usize], Vec<i32>) {
+    let mut work = vec![[0; 2]; worksz];
+    let mut spaixd = [0.0; SPICE_DSK02_SPADSZ as usize];
+    let mut spaixi = vec![0; spxisz];
+    unsafe {
+        crate::c::dskmi2_c(
+            vrtces.len() as _,
+            vrtces.as_ptr() as *mut _,
+            plates.len() as _,
+            plates.as_ptr() as *mut _,
+            finscl,
+            corscl,
+            worksz as _,
+            voxpsz,
+            voxlsz,
+            makvtl as _,
+            spxisz as _,
+            work.as_mut_ptr(),
+            spaixd.as_mut_ptr(),
+            spaixi.as_mut_ptr(),
+        )
+    };
+    (spaixd, spaixi)
+}
+
+/**
+Open a new DSK file for subsequent write operations.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn dskopn(fname: &str, ifname: &str, ncomch: i32) -> i32 {
+    let fname = CString::new(fname).unwrap();
+    let ifname = CString::new(ifname).unwrap();
+    let mut handle = 0;
+    unsafe {
+        crate::c::dskopn_c(
+            fname.as_ptr() as *mut _,
+            ifname.as_ptr() as *mut _,
+            ncomch,
+            &mut handle,
+        )
+    };
+    handle
+}
+
 cspice_proc! {
     /**
     Compute the unit normal vector for a specified plate from a type 2 DSK segment.
@@ -311,6 +664,94 @@ pub fn dskv02(handle: i32, mut dladsc: DLADSC, start: usize, room: usize) -> Vec
     vrtces
 }
 
+/**
+Determine range bounds for a set of triangular plates to be stored in a type 2 DSK segment.
+
+Return the bounds of the third coordinate of the coordinate system `corsys` of parameters
+`corpar`.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn dskrb2(
+    vrtces: &[[f64; 3]],
+    plates: &[[i32; 3]],
+    corsys: i32,
+    corpar: [f64; SPICE_DSK_NSYPAR as usize],
+) -> (f64, f64) {
+    let mut corpar = corpar;
+    let mut mncor3 = 0.0;
+    let mut mxcor3 = 0.0;
+    unsafe {
+        crate::c::dskrb2_c(
+            vrtces.len() as _,
+            vrtces.as_ptr() as *mut _,
+            plates.len() as _,
+            plates.as_ptr() as *mut _,
+            corsys,
+            corpar.as_mut_ptr(),
+            &mut mncor3,
+            &mut mxcor3,
+        )
+    };
+    (mncor3, mxcor3)
+}
+
+/**
+Write a type 2 segment to a DSK file.
+
+The spatial index is the one built by [`dskmi2`].
+*/
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn dskw02(
+    handle: i32,
+    center: i32,
+    surfce: i32,
+    dclass: i32,
+    frame: &str,
+    corsys: i32,
+    corpar: [f64; SPICE_DSK_NSYPAR as usize],
+    mncor1: f64,
+    mxcor1: f64,
+    mncor2: f64,
+    mxcor2: f64,
+    mncor3: f64,
+    mxcor3: f64,
+    first: f64,
+    last: f64,
+    vrtces: &[[f64; 3]],
+    plates: &[[i32; 3]],
+    spaixd: &[f64],
+    spaixi: &[i32],
+) {
+    let frame = CString::new(frame).unwrap();
+    let mut corpar = corpar;
+    unsafe {
+        crate::c::dskw02_c(
+            handle,
+            center,
+            surfce,
+            dclass,
+            frame.as_ptr() as *mut _,
+            corsys,
+            corpar.as_mut_ptr(),
+            mncor1,
+            mxcor1,
+            mncor2,
+            mxcor2,
+            mncor3,
+            mxcor3,
+            first,
+            last,
+            vrtces.len() as _,
+            vrtces.as_ptr() as *mut _,
+            plates.len() as _,
+            plates.as_ptr() as *mut _,
+            spaixd.as_ptr() as *mut _,
+            spaixi.as_ptr() as *mut _,
+        )
+    };
+}
+
 cspice_proc! {
     /**
     Determine the plate ID and body-fixed coordinates of the intersection of a specified ray with
@@ -326,6 +767,114 @@ cspice_proc! {
     }
 }
 
+/**
+Compute ray-surface intercepts for a set of rays, using data provided by multiple loaded DSK
+segments.
+
+`pri` tells whether only the surfaces of `srflst` are used, every surface being used if it is
+empty. The rays are the pairs of `vtxarr` and `dirarr`, which must have the same length, and the
+intercepts are returned with the flags telling whether they were found.
+*/
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn dskxv(
+    pri: bool,
+    target: &str,
+    srflst: &[i32],
+    et: f64,
+    fixref: &str,
+    vtxarr: &[[f64; 3]],
+    dirarr: &[[f64; 3]],
+) -> (Vec<[f64; 3]>, Vec<bool>) {
+    assert_eq!(
+        vtxarr.len(),
+        dirarr.len(),
+        "as many vertices as directions are expected"
+    );
+    let target = CString::new(target).unwrap();
+    let fixref = CString::new(fixref).unwrap();
+    let mut xptarr = vec![[0.0; 3]; vtxarr.len()];
+    let mut fndarr = vec![0; vtxarr.len()];
+    unsafe {
+        crate::c::dskxv_c(
+            pri as _,
+            target.as_ptr() as *mut _,
+            srflst.len() as _,
+            srflst.as_ptr() as *mut _,
+            et,
+            fixref.as_ptr() as *mut _,
+            vtxarr.len() as _,
+            vtxarr.as_ptr() as *mut _,
+            dirarr.as_ptr() as *mut _,
+            xptarr.as_mut_ptr(),
+            fndarr.as_mut_ptr(),
+        )
+    };
+    (xptarr, fndarr.into_iter().map(|found| found != 0).collect())
+}
+
+/**
+Compute a ray-surface intercept using data provided by multiple loaded DSK segments, and return
+information about the source of the data defining the surface on which the intercept was found.
+
+The surfaces are selected as in [`dskxv`]. Return the intercept, the handle and the descriptors
+of the segment, its double precision and integer components, such as the plate ID for type 2
+segments, and whether the intercept was found.
+*/
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn dskxsi(
+    pri: bool,
+    target: &str,
+    srflst: &[i32],
+    et: f64,
+    fixref: &str,
+    vertex: [f64; 3],
+    raydir: [f64; 3],
+) -> (
+    [f64; 3],
+    i32,
+    DLADSC,
+    DSKDSC,
+    [f64; SPICE_DSKXSI_DCSIZE as usize],
+    [i32; SPICE_DSKXSI_ICSIZE as usize],
+    bool,
+) {
+    let target = CString::new(target).unwrap();
+    let fixref = CString::new(fixref).unwrap();
+    let mut vertex = vertex;
+    let mut raydir = raydir;
+    let mut xpt = [0.0; 3];
+    let mut handle = 0;
+    let mut dladsc: DLADSC = unsafe { std::mem::zeroed() };
+    let mut dskdsc: DSKDSC = unsafe { std::mem::zeroed() };
+    let mut dc = [0.0; SPICE_DSKXSI_DCSIZE as usize];
+    let mut ic = [0; SPICE_DSKXSI_ICSIZE as usize];
+    let mut found = 0;
+    unsafe {
+        crate::c::dskxsi_c(
+            pri as _,
+            target.as_ptr() as *mut _,
+            srflst.len() as _,
+            srflst.as_ptr() as *mut _,
+            et,
+            fixref.as_ptr() as *mut _,
+            vertex.as_mut_ptr(),
+            raydir.as_mut_ptr(),
+            dc.len() as _,
+            ic.len() as _,
+            xpt.as_mut_ptr(),
+            &mut handle,
+            &mut dladsc,
+            &mut dskdsc,
+            dc.as_mut_ptr(),
+            ic.as_mut_ptr(),
+            &mut found,
+        )
+    };
+    (xpt, handle, dladsc, dskdsc, dc, ic, found != 0)
+}
+
 cspice_proc! {
     /**
     Return plate model size parameters---plate count and
@@ -340,6 +889,55 @@ cspice_proc! {
     pub fn dskz02(handle: i32, dladsc: DLADSC) -> (i32, i32) {}
 }
 
+cspice_proc! {
+    /**
+    Compute the Jacobian of the transformation from rectangular to spherical coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dsphdr(x: f64, y: f64, z: f64) -> [[f64; 3]; 3] {}
+}
+
+/**
+Return the number of values and the type, `'C'` for characters or `'N'` for numbers, of a
+variable of the kernel pool, and whether the variable is present.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn dtpool(name: &str) -> (usize, char, bool) {
+    let name = CString::new(name).unwrap();
+    let mut found = 0;
+    let mut n = 0;
+    let mut kind = 0;
+    unsafe { crate::c::dtpool_c(name.as_ptr() as *mut _, &mut found, &mut n, &mut kind) };
+    (n as _, kind as u8 as char, found != 0)
+}
+
+/**
+Find the limb of a triaxial ellipsoid, viewed from a given point.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn edlimb(a: f64, b: f64, c: f64, viewpt: [f64; 3]) -> Ellipse {
+    let mut viewpt = viewpt;
+    let mut limb = SpiceEllipse::from(Ellipse::default());
+    unsafe { crate::c::edlimb_c(a, b, c, viewpt.as_mut_ptr(), &mut limb) };
+    limb.into()
+}
+
+cspice_proc! {
+    /**
+    Construct a rotation matrix from a set of Euler angles, as the product
+    `[angle3]_axis3 [angle2]_axis2 [angle1]_axis1` of frame rotations.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn eul2m(
+        angle3: f64,
+        angle2: f64,
+        angle1: f64,
+        axis3: i32,
+        axis2: i32,
+        axis1: i32,
+    ) -> [[f64; 3]; 3] {}
+}
+
 /**
 Return the d.p. value of a kernel variable from the kernel pool.
 */
@@ -409,29 +1007,184 @@ pub fn getfov(
     (fcstr!(shape), fcstr!(frame), bsight, bounds)
 }
 
+/**
+Find the illumination angles (phase, solar incidence, and emission) at a specified surface point
+of a target body.
+
+Return the epoch of the surface point, the vector from the observer to the point, and the phase,
+incidence and emission angles.
+
+This function has a [neat version][crate::neat::ilumin].
+*/
+#[allow(clippy::too_many_arguments)]
+pub fn ilumin(
+    method: impl Into<ShapeModel>,
+    target: &str,
+    et: f64,
+    fixref: &str,
+    abcorr: impl Into<Aberration>,
+    obsrvr: &str,
+    mut spoint: [f64; 3],
+) -> (f64, [f64; 3], f64, f64, f64) {
+    let method = CString::new(method.into().to_string()).unwrap();
+    let target = CString::new(target).unwrap();
+    let fixref = CString::new(fixref).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let obsrvr = CString::new(obsrvr).unwrap();
+    let mut trgepc = 0.0;
+    let mut srfvec = [0.0; 3];
+    let mut phase = 0.0;
+    let mut incdnc = 0.0;
+    let mut emissn = 0.0;
+    unsafe {
+        crate::c::ilumin_c(
+            method.as_ptr() as *mut _,
+            target.as_ptr() as *mut _,
+            et,
+            fixref.as_ptr() as *mut _,
+            abcorr,
+            obsrvr.as_ptr() as *mut _,
+            spoint.as_mut_ptr(),
+            &mut trgepc,
+            srfvec.as_mut_ptr(),
+            &mut phase,
+            &mut incdnc,
+            &mut emissn,
+        )
+    };
+    (trgepc, srfvec, phase, incdnc, emissn)
+}
+
+/**
+Find the illumination angles (phase, incidence, and emission) at a specified surface point of a
+target body, the illumination source being a specified ephemeris object.
+
+Return the epoch of the surface point, the vector from the observer to the point, and the phase,
+incidence and emission angles.
+
+This function has a [neat version][crate::neat::illumg].
+*/
+#[allow(clippy::too_many_arguments)]
+pub fn illumg(
+    method: impl Into<ShapeModel>,
+    target: &str,
+    ilusrc: &str,
+    et: f64,
+    fixref: &str,
+    abcorr: impl Into<Aberration>,
+    obsrvr: &str,
+    mut spoint: [f64; 3],
+) -> (f64, [f64; 3], f64, f64, f64) {
+    let method = CString::new(method.into().to_string()).unwrap();
+    let target = CString::new(target).unwrap();
+    let ilusrc = CString::new(ilusrc).unwrap();
+    let fixref = CString::new(fixref).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let obsrvr = CString::new(obsrvr).unwrap();
+    let mut trgepc = 0.0;
+    let mut srfvec = [0.0; 3];
+    let mut phase = 0.0;
+    let mut incdnc = 0.0;
+    let mut emissn = 0.0;
+    unsafe {
+        crate::c::illumg_c(
+            method.as_ptr() as *mut _,
+            target.as_ptr() as *mut _,
+            ilusrc.as_ptr() as *mut _,
+            et,
+            fixref.as_ptr() as *mut _,
+            abcorr,
+            obsrvr.as_ptr() as *mut _,
+            spoint.as_mut_ptr(),
+            &mut trgepc,
+            srfvec.as_mut_ptr(),
+            &mut phase,
+            &mut incdnc,
+            &mut emissn,
+        )
+    };
+    (trgepc, srfvec, phase, incdnc, emissn)
+}
+
+/**
+Compute the illumination angles---phase, incidence, and emission---at a specified point on a
+target body. Return logical flags indicating whether the surface point is visible from the
+observer's position and whether the surface point is illuminated.
+
+The target body's surface is represented using topographic data provided by DSK files, or by a
+reference ellipsoid.
+
+The illumination source is a specified ephemeris object.
+*/
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn illumf(
+    method: impl Into<ShapeModel>,
+    target: &str,
+    ilusrc: &str,
+    et: f64,
+    fixref: &str,
+    abcorr: impl Into<Aberration>,
+    obsrvr: &str,
+    mut spoint: [f64; 3],
+) -> (f64, [f64; 3], f64, f64, f64, bool, bool) {
+    let method = CString::new(method.into().to_string()).unwrap();
+    let target = CString::new(target).unwrap();
+    let ilusrc = CString::new(ilusrc).unwrap();
+    let fixref = CString::new(fixref).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let obsrvr = CString::new(obsrvr).unwrap();
+    let mut trgepc = 0.0;
+    let mut srfvec = [0.0; 3];
+    let mut phase = 0.0;
+    let mut incdnc = 0.0;
+    let mut emissn = 0.0;
+    let mut visibl = 0;
+    let mut lit = 0;
+    unsafe {
+        crate::c::illumf_c(
+            method.as_ptr() as *mut _,
+            target.as_ptr() as *mut _,
+            ilusrc.as_ptr() as *mut _,
+            et,
+            fixref.as_ptr() as *mut _,
+            abcorr,
+            obsrvr.as_ptr() as *mut _,
+            spoint.as_mut_ptr(),
+            &mut trgepc,
+            srfvec.as_mut_ptr(),
+            &mut phase,
+            &mut incdnc,
+            &mut emissn,
+            &mut visibl,
+            &mut lit,
+        )
+    };
+    (trgepc, srfvec, phase, incdnc, emissn, visibl != 0, lit != 0)
+}
+
+/**
+Convert an input time from ephemeris seconds past J2000 to Calendar, Day-of-Year, or Julian Date
+format, UTC.
+
+This function has a [neat version][crate::neat::et2utc].
+*/
+pub fn et2utc(et: f64, format: impl Into<UtcFormat>, prec: i32, lenout: usize) -> String {
+    let format = CString::new(format.into().as_str()).unwrap();
+    let varout_0 = mallocstr!(lenout);
+    unsafe {
+        crate::c::et2utc_c(et, format.as_ptr() as *mut _, prec, lenout as i32, varout_0);
+    }
+    fcstr!(varout_0)
+}
+
 cspice_proc! {
     /**
-    Compute the illumination angles---phase, incidence, and emission---at a specified point on a
-    target body. Return logical flags indicating whether the surface point is visible from the
-    observer's position and whether the surface point is illuminated.
-
-    The target body's surface is represented using topographic data provided by DSK files, or by a
-    reference ellipsoid.
+    Retrieve the name of a reference frame associated with a SPICE ID code.
 
-    The illumination source is a specified ephemeris object.
+    This function has a [neat version][crate::neat::frmnam].
     */
-    #[allow(clippy::too_many_arguments)]
-    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn illumf(
-        method: &str,
-        target: &str,
-        ilusrc: &str,
-        et: f64,
-        fixref: &str,
-        abcorr: &str,
-        obsrvr: &str,
-        spoint: [f64; 3]
-    ) -> (f64, [f64; 3], f64, f64, f64, bool, bool) {}
+    pub fn frmnam(frcode: i32, lenout: i32) -> String {}
 }
 
 cspice_proc! {
@@ -442,6 +1195,43 @@ cspice_proc! {
     pub fn furnsh(name: &str) {}
 }
 
+/**
+Find the intersection of a triaxial ellipsoid and a plane.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn inedpl(a: f64, b: f64, c: f64, plane: Plane) -> (Ellipse, bool) {
+    let mut plane = SpicePlane::from(plane);
+    let mut ellipse = SpiceEllipse::from(Ellipse::default());
+    let mut found = 0;
+    unsafe { crate::c::inedpl_c(a, b, c, &mut plane, &mut ellipse, &mut found) };
+    (ellipse.into(), found != 0)
+}
+
+/**
+Find the intersection of a ray and a plane.
+
+The number of intersection points is 0 or 1, or -1 if the ray lies in the plane, in which case
+the vertex is returned.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn inrypl(vertex: [f64; 3], dir: [f64; 3], plane: Plane) -> (i32, [f64; 3]) {
+    let mut vertex = vertex;
+    let mut dir = dir;
+    let mut plane = SpicePlane::from(plane);
+    let mut nxpts = 0;
+    let mut xpt = [0.0; 3];
+    unsafe {
+        crate::c::inrypl_c(
+            vertex.as_mut_ptr(),
+            dir.as_mut_ptr(),
+            &mut plane,
+            &mut nxpts,
+            xpt.as_mut_ptr(),
+        )
+    };
+    (nxpts, xpt)
+}
+
 cspice_proc! {
     /**
     Clear the KEEPER subsystem: unload all kernels, clear the kernel pool, and re-initialize the
@@ -493,6 +1283,39 @@ pub fn kdata(
     }
 }
 
+/**
+Map array of planetocentric longitude/latitude coordinate pairs to surface points on a specified
+target body.
+
+The surface of the target body may be represented by a triaxial ellipsoid or by topographic data
+provided by DSK files.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn latsrf(
+    method: impl Into<ShapeModel>,
+    target: &str,
+    et: f64,
+    fixref: &str,
+    lonlat: &[[f64; 2]],
+) -> Vec<[f64; 3]> {
+    let method = CString::new(method.into().to_string()).unwrap();
+    let target = CString::new(target).unwrap();
+    let fixref = CString::new(fixref).unwrap();
+    let mut srfpts = vec![[0.0; 3]; lonlat.len()];
+    unsafe {
+        crate::c::latsrf_c(
+            method.as_ptr() as *mut _,
+            target.as_ptr() as *mut _,
+            et,
+            fixref.as_ptr() as *mut _,
+            lonlat.len() as _,
+            lonlat.as_ptr() as *mut _,
+            srfpts.as_mut_ptr(),
+        )
+    };
+    srfpts
+}
+
 cspice_proc! {
     /**
     Return the current number of kernels that have been loaded via the KEEPER interface that are of
@@ -510,6 +1333,89 @@ cspice_proc! {
     pub fn latrec(radius: f64, longitude: f64, latitude: f64) -> [f64; 3] {}
 }
 
+/**
+Find limb points on a target body. The limb is the set of points of tangency on the target of
+rays emanating from the observer.
+
+The half-planes of the cuts contain the observer-target center axis. The first one contains
+`refvec`, and the next ones are rotated by `rolstp` radians about the axis. The points are searched
+with the step `schstp` and the tolerance `soltol`, both in radians, and at most `maxn` points are
+returned: the number of points found in each cut, the points, their epochs and the tangent vectors
+from the observer to them.
+
+This function has a [neat version][crate::neat::limbpt].
+*/
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn limbpt(
+    method: &str,
+    target: &str,
+    et: f64,
+    fixref: &str,
+    abcorr: impl Into<Aberration>,
+    corloc: &str,
+    obsrvr: &str,
+    mut refvec: [f64; 3],
+    rolstp: f64,
+    ncuts: usize,
+    schstp: f64,
+    soltol: f64,
+    maxn: usize,
+) -> (Vec<i32>, Vec<[f64; 3]>, Vec<f64>, Vec<[f64; 3]>) {
+    let method = CString::new(method).unwrap();
+    let target = CString::new(target).unwrap();
+    let fixref = CString::new(fixref).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let corloc = CString::new(corloc).unwrap();
+    let obsrvr = CString::new(obsrvr).unwrap();
+    let mut npts = vec![0; ncuts];
+    let mut points = vec![[0.0; 3]; maxn];
+    let mut epochs = vec![0.0; maxn];
+    let mut tangts = vec![[0.0; 3]; maxn];
+    unsafe {
+        crate::c::limbpt_c(
+            method.as_ptr() as *mut _,
+            target.as_ptr() as *mut _,
+            et,
+            fixref.as_ptr() as *mut _,
+            abcorr,
+            corloc.as_ptr() as *mut _,
+            obsrvr.as_ptr() as *mut _,
+            refvec.as_mut_ptr(),
+            rolstp,
+            ncuts as _,
+            schstp,
+            soltol,
+            maxn as _,
+            npts.as_mut_ptr(),
+            points.as_mut_ptr(),
+            epochs.as_mut_ptr(),
+            tangts.as_mut_ptr(),
+        )
+    };
+    let total = npts.iter().sum::<i32>().max(0) as usize;
+    points.truncate(total);
+    epochs.truncate(total);
+    tangts.truncate(total);
+    (npts, points, epochs, tangts)
+}
+
+cspice_proc! {
+    /**
+    Factor a rotation matrix as a product of three rotations about specified coordinate axes,
+    returning `angle3`, `angle2` and `angle1` as expected by [`eul2m`].
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn m2eul(r: [[f64; 3]; 3], axis3: i32, axis2: i32, axis1: i32) -> (f64, f64, f64) {}
+}
+
+cspice_proc! {
+    /**
+    Find a unit quaternion corresponding to a specified rotation matrix.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn m2q(r: [[f64; 3]; 3]) -> [f64; 4] {}
+}
+
 cspice_proc! {
     /**
        Multiply a 3x3 double precision matrix with a 3-dimensional double precision vector.
@@ -520,23 +1426,185 @@ cspice_proc! {
 
 cspice_proc! {
     /**
-    Determines the occultation condition (not occulted, partially, etc.) of one target relative to
-    another target as seen by an observer at a given time, with targets modeled as points,
-    ellipsoids, or digital shapes (DSK)
+    Locate the point on the surface of an ellipsoid nearest to a given position, and the altitude
+    of the position above the ellipsoid.
     */
-    #[allow(clippy::too_many_arguments)]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn occult(
-        targ1: &str,
-        shape1: &str,
-        frame1: &str,
-        targ2: &str,
-        shape2: &str,
-        frame2: &str,
-        abcorr: &str,
-        obsrvr: &str,
-        et: f64,
-    ) -> i32 {}
+    pub fn nearpt(positn: [f64; 3], a: f64, b: f64, c: f64) -> ([f64; 3], f64) {}
+}
+
+cspice_proc! {
+    /**
+    Find the point on a triaxial ellipsoid nearest to a line, and the distance between them.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn npedln(a: f64, b: f64, c: f64, linept: [f64; 3], linedr: [f64; 3]) -> ([f64; 3], f64) {}
+}
+
+/**
+Find the point on an ellipse nearest to a given point, and the distance between them.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn npelpt(point: [f64; 3], ellips: Ellipse) -> ([f64; 3], f64) {
+    let mut point = point;
+    let mut ellips = SpiceEllipse::from(ellips);
+    let mut pnear = [0.0; 3];
+    let mut dist = 0.0;
+    unsafe {
+        crate::c::npelpt_c(
+            point.as_mut_ptr(),
+            &mut ellips,
+            pnear.as_mut_ptr(),
+            &mut dist,
+        )
+    };
+    (pnear, dist)
+}
+
+/**
+Make a plane from a normal vector and a constant.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn nvc2pl(normal: [f64; 3], constant: f64) -> Plane {
+    let mut normal = normal;
+    let mut plane = SpicePlane::from(Plane::default());
+    unsafe { crate::c::nvc2pl_c(normal.as_mut_ptr(), constant, &mut plane) };
+    plane.into()
+}
+
+/**
+Make a plane from a normal vector and a point.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn nvp2pl(normal: [f64; 3], point: [f64; 3]) -> Plane {
+    let mut normal = normal;
+    let mut point = point;
+    let mut plane = SpicePlane::from(Plane::default());
+    unsafe { crate::c::nvp2pl_c(normal.as_mut_ptr(), point.as_mut_ptr(), &mut plane) };
+    plane.into()
+}
+
+/**
+Determines the occultation condition (not occulted, partially, etc.) of one target relative to
+another target as seen by an observer at a given time, with targets modeled as points,
+ellipsoids, or digital shapes (DSK)
+*/
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn occult(
+    targ1: &str,
+    shape1: &str,
+    frame1: &str,
+    targ2: &str,
+    shape2: &str,
+    frame2: &str,
+    abcorr: impl Into<Aberration>,
+    obsrvr: &str,
+    et: f64,
+) -> i32 {
+    let targ1 = CString::new(targ1).unwrap();
+    let shape1 = CString::new(shape1).unwrap();
+    let frame1 = CString::new(frame1).unwrap();
+    let targ2 = CString::new(targ2).unwrap();
+    let shape2 = CString::new(shape2).unwrap();
+    let frame2 = CString::new(frame2).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let obsrvr = CString::new(obsrvr).unwrap();
+    let mut ocltid = 0;
+    unsafe {
+        crate::c::occult_c(
+            targ1.as_ptr() as *mut _,
+            shape1.as_ptr() as *mut _,
+            frame1.as_ptr() as *mut _,
+            targ2.as_ptr() as *mut _,
+            shape2.as_ptr() as *mut _,
+            frame2.as_ptr() as *mut _,
+            abcorr,
+            obsrvr.as_ptr() as *mut _,
+            et,
+            &mut ocltid,
+        )
+    };
+    ocltid
+}
+
+/**
+Compute the apparent phase angle for a target, observer, illuminator set of ephemeris objects.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn phaseq(
+    et: f64,
+    target: &str,
+    illmn: &str,
+    obsrvr: &str,
+    abcorr: impl Into<Aberration>,
+) -> f64 {
+    let target = CString::new(target).unwrap();
+    let illmn = CString::new(illmn).unwrap();
+    let obsrvr = CString::new(obsrvr).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    unsafe {
+        crate::c::phaseq_c(
+            et,
+            target.as_ptr() as *mut _,
+            illmn.as_ptr() as *mut _,
+            obsrvr.as_ptr() as *mut _,
+            abcorr,
+        )
+    }
+}
+
+/**
+Convert planetographic coordinates to rectangular coordinates.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn pgrrec(body: &str, lon: f64, lat: f64, alt: f64, re: f64, f: f64) -> [f64; 3] {
+    let body = CString::new(body).unwrap();
+    let mut rectan = [0.0; 3];
+    unsafe {
+        crate::c::pgrrec_c(
+            body.as_ptr() as *mut _,
+            lon,
+            lat,
+            alt,
+            re,
+            f,
+            rectan.as_mut_ptr(),
+        )
+    };
+    rectan
+}
+
+/**
+Return a unit normal vector and constant that define a plane.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn pl2nvc(plane: Plane) -> ([f64; 3], f64) {
+    let mut plane = SpicePlane::from(plane);
+    let mut normal = [0.0; 3];
+    let mut constant = 0.0;
+    unsafe { crate::c::pl2nvc_c(&mut plane, normal.as_mut_ptr(), &mut constant) };
+    (normal, constant)
+}
+
+/**
+Make a plane from a point and two spanning vectors.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn psv2pl(point: [f64; 3], span1: [f64; 3], span2: [f64; 3]) -> Plane {
+    let mut point = point;
+    let mut span1 = span1;
+    let mut span2 = span2;
+    let mut plane = SpicePlane::from(Plane::default());
+    unsafe {
+        crate::c::psv2pl_c(
+            point.as_mut_ptr(),
+            span1.as_mut_ptr(),
+            span2.as_mut_ptr(),
+            &mut plane,
+        )
+    };
+    plane.into()
 }
 
 cspice_proc! {
@@ -557,6 +1625,23 @@ cspice_proc! {
     pub fn pxfrm2(from: &str, to: &str, etfrom: f64, etto: f64) -> [[f64; 3]; 3] {}
 }
 
+cspice_proc! {
+    /**
+    Find the rotation matrix corresponding to a specified unit quaternion.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn q2m(q: [f64; 4]) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Compute the axis of the rotation given by an input matrix and the angle of the rotation about
+    that axis.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn raxisa(matrix: [[f64; 3]; 3]) -> ([f64; 3], f64) {}
+}
+
 cspice_proc! {
     /**
     Convert range, right ascension, and declination to rectangular coordinates
@@ -565,6 +1650,55 @@ cspice_proc! {
     pub fn radrec(range: f64, ra: f64, dec: f64) -> [f64; 3] {}
 }
 
+/**
+Convert rectangular coordinates to range, azimuth and elevation.
+
+`azccw` tells whether the azimuth increases counterclockwise about the positive Z axis, and
+`elplsz` whether the elevation increases towards it.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn recazl(rectan: [f64; 3], azccw: bool, elplsz: bool) -> (f64, f64, f64) {
+    let mut rectan = rectan;
+    let mut range = 0.0;
+    let mut az = 0.0;
+    let mut el = 0.0;
+    unsafe {
+        crate::c::recazl_c(
+            rectan.as_mut_ptr(),
+            azccw as _,
+            elplsz as _,
+            &mut range,
+            &mut az,
+            &mut el,
+        )
+    };
+    (range, az, el)
+}
+
+cspice_proc! {
+    /**
+    Convert from rectangular coordinates to cylindrical coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn reccyl(rectan: [f64; 3]) -> (f64, f64, f64) {}
+}
+
+cspice_proc! {
+    /**
+    Convert from rectangular coordinates to geodetic coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn recgeo(rectan: [f64; 3], re: f64, f: f64) -> (f64, f64, f64) {}
+}
+
+cspice_proc! {
+    /**
+    Convert from rectangular coordinates to latitudinal coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn reclat(rectan: [f64; 3]) -> (f64, f64, f64) {}
+}
+
 /**
 Convert rectangular coordinates to planetographic coordinates.
 */
@@ -589,26 +1723,78 @@ cspice_proc! {
 
 cspice_proc! {
     /**
-    Compute, for a given observer and a ray emanating from the
-    observer, the surface intercept of the ray on a target body at
-    a specified epoch, optionally corrected for light time and
-    stellar aberration.
+    Convert from rectangular coordinates to spherical coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn recsph(rectan: [f64; 3]) -> (f64, f64, f64) {}
+}
 
-    The surface of the target body may be represented by a triaxial
-    ellipsoid or by topographic data provided by DSK files.
+cspice_proc! {
+    /**
+    Find semi-axis vectors of an ellipse generated by two arbitrary three-dimensional vectors.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn saelgv(vec1: [f64; 3], vec2: [f64; 3]) -> ([f64; 3], [f64; 3]) {}
+}
+
+/**
+Compute, for a given observer and a ray emanating from the
+observer, the surface intercept of the ray on a target body at
+a specified epoch, optionally corrected for light time and
+stellar aberration.
+
+The surface of the target body may be represented by a triaxial
+ellipsoid or by topographic data provided by DSK files.
+
+This routine supersedes srfxpt.
+*/
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn sincpt(
+    method: impl Into<ShapeModel>,
+    target: &str,
+    et: f64,
+    fixref: &str,
+    abcorr: impl Into<Aberration>,
+    obsrvr: &str,
+    dref: &str,
+    mut dvec: [f64; 3],
+) -> ([f64; 3], f64, [f64; 3], bool) {
+    let method = CString::new(method.into().to_string()).unwrap();
+    let target = CString::new(target).unwrap();
+    let fixref = CString::new(fixref).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let obsrvr = CString::new(obsrvr).unwrap();
+    let dref = CString::new(dref).unwrap();
+    let mut spoint = [0.0; 3];
+    let mut trgepc = 0.0;
+    let mut srfvec = [0.0; 3];
+    let mut found = 0;
+    unsafe {
+        crate::c::sincpt_c(
+            method.as_ptr() as *mut _,
+            target.as_ptr() as *mut _,
+            et,
+            fixref.as_ptr() as *mut _,
+            abcorr,
+            obsrvr.as_ptr() as *mut _,
+            dref.as_ptr() as *mut _,
+            dvec.as_mut_ptr(),
+            spoint.as_mut_ptr(),
+            &mut trgepc,
+            srfvec.as_mut_ptr(),
+            &mut found,
+        )
+    };
+    (spoint, trgepc, srfvec, found != 0)
+}
 
-    This routine supersedes srfxpt.
+cspice_proc! {
+    /**
+    Convert from spherical coordinates to rectangular coordinates.
     */
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn sincpt(
-        method:&str,
-        target: &str,
-        et: f64,
-        fixred: &str,
-        abcorr: &str,
-        obsrvr: &str,
-        dref: &str,
-        dvec: [f64; 3]) -> ([f64; 3], f64, [f64; 3], bool) {}
+    pub fn sphrec(r: f64, colat: f64, lon: f64) -> [f64; 3] {}
 }
 
 cspice_proc! {
@@ -636,23 +1822,327 @@ cspice_proc! {
     pub fn spkw09(handle: i32, body: i32, center: i32, frame: &str, first: f64, last: f64, segid: &str, degree: i32, n: i32, states: &mut [[f64; 6]], epochs: &mut [f64]) {}
 }
 
+/**
+Return the position of a target body relative to an observing body, optionally corrected for
+light time (planetary aberration) and stellar aberration.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn spkpos(
+    targ: &str,
+    et: f64,
+    frame: &str,
+    abcorr: impl Into<Aberration>,
+    obs: &str,
+) -> ([f64; 3], f64) {
+    let targ = CString::new(targ).unwrap();
+    let frame = CString::new(frame).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let obs = CString::new(obs).unwrap();
+    let mut ptarg = [0.0; 3];
+    let mut lt = 0.0;
+    unsafe {
+        crate::c::spkpos_c(
+            targ.as_ptr() as *mut _,
+            et,
+            frame.as_ptr() as *mut _,
+            abcorr,
+            obs.as_ptr() as *mut _,
+            ptarg.as_mut_ptr(),
+            &mut lt,
+        )
+    };
+    (ptarg, lt)
+}
+
+/**
+Return the state (position and velocity) of a target body
+relative to an observing body, optionally corrected for light
+time (planetary aberration) and stellar aberration.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn spkezr(
+    targ: &str,
+    et: f64,
+    frame: &str,
+    abcorr: impl Into<Aberration>,
+    obs: &str,
+) -> ([f64; 6], f64) {
+    let targ = CString::new(targ).unwrap();
+    let frame = CString::new(frame).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let obs = CString::new(obs).unwrap();
+    let mut starg = [0.0; 6];
+    let mut lt = 0.0;
+    unsafe {
+        crate::c::spkezr_c(
+            targ.as_ptr() as *mut _,
+            et,
+            frame.as_ptr() as *mut _,
+            abcorr,
+            obs.as_ptr() as *mut _,
+            starg.as_mut_ptr(),
+            &mut lt,
+        )
+    };
+    (starg, lt)
+}
+
+/**
+Return the state (position and velocity) of a target body relative to an observing body,
+optionally corrected for light time (planetary aberration) and stellar aberration.
+
+Bodies are given by their NAIF ID, which skips the name translation of [`spkezr`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn spkez(
+    targ: i32,
+    et: f64,
+    frame: &str,
+    abcorr: impl Into<Aberration>,
+    obs: i32,
+) -> ([f64; 6], f64) {
+    let frame = CString::new(frame).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let mut starg = [0.0; 6];
+    let mut lt = 0.0;
+    unsafe {
+        crate::c::spkez_c(
+            targ,
+            et,
+            frame.as_ptr() as *mut _,
+            abcorr,
+            obs,
+            starg.as_mut_ptr(),
+            &mut lt,
+        )
+    };
+    (starg, lt)
+}
+
+/**
+Return the position of a target body relative to an observing body, optionally corrected for
+light time (planetary aberration) and stellar aberration.
+
+Bodies are given by their NAIF ID, which skips the name translation of [`spkpos`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn spkezp(
+    targ: i32,
+    et: f64,
+    frame: &str,
+    abcorr: impl Into<Aberration>,
+    obs: i32,
+) -> ([f64; 3], f64) {
+    let frame = CString::new(frame).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let mut ptarg = [0.0; 3];
+    let mut lt = 0.0;
+    unsafe {
+        crate::c::spkezp_c(
+            targ,
+            et,
+            frame.as_ptr() as *mut _,
+            abcorr,
+            obs,
+            ptarg.as_mut_ptr(),
+            &mut lt,
+        )
+    };
+    (ptarg, lt)
+}
+
+/**
+Compute the geometric state (position and velocity) of a target body relative to an observing
+body.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn spkgeo(targ: i32, et: f64, frame: &str, obs: i32) -> ([f64; 6], f64) {
+    let frame = CString::new(frame).unwrap();
+    let mut state = [0.0; 6];
+    let mut lt = 0.0;
+    unsafe {
+        crate::c::spkgeo_c(
+            targ,
+            et,
+            frame.as_ptr() as *mut _,
+            obs,
+            state.as_mut_ptr(),
+            &mut lt,
+        )
+    };
+    (state, lt)
+}
+
+/**
+Compute the geometric position of a target body relative to an observing body.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn spkgps(targ: i32, et: f64, frame: &str, obs: i32) -> ([f64; 3], f64) {
+    let frame = CString::new(frame).unwrap();
+    let mut pos = [0.0; 3];
+    let mut lt = 0.0;
+    unsafe {
+        crate::c::spkgps_c(
+            targ,
+            et,
+            frame.as_ptr() as *mut _,
+            obs,
+            pos.as_mut_ptr(),
+            &mut lt,
+        )
+    };
+    (pos, lt)
+}
+
+/**
+Return the state (position and velocity) of a target body relative to the solar system
+barycenter.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn spkssb(targ: i32, et: f64, frame: &str) -> [f64; 6] {
+    let frame = CString::new(frame).unwrap();
+    let mut starg = [0.0; 6];
+    unsafe { crate::c::spkssb_c(targ, et, frame.as_ptr() as *mut _, starg.as_mut_ptr()) };
+    starg
+}
+
+/**
+Return the position of a target body relative to an observer, optionally corrected for light
+time and stellar aberration.
+
+The observer is given by its state relative to the solar system barycenter, in the inertial
+frame `frame`.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn spkapo(
+    targ: i32,
+    et: f64,
+    frame: &str,
+    mut sobs: [f64; 6],
+    abcorr: impl Into<Aberration>,
+) -> ([f64; 3], f64) {
+    let frame = CString::new(frame).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let mut ptarg = [0.0; 3];
+    let mut lt = 0.0;
+    unsafe {
+        crate::c::spkapo_c(
+            targ,
+            et,
+            frame.as_ptr() as *mut _,
+            sobs.as_mut_ptr(),
+            abcorr,
+            ptarg.as_mut_ptr(),
+            &mut lt,
+        )
+    };
+    (ptarg, lt)
+}
+
+/**
+Return the state (position and velocity) of a target body relative to an observer, optionally
+corrected for light time and stellar aberration, expressed relative to an inertial reference
+frame.
+
+Light time derivative is returned last.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn spkacs(
+    targ: i32,
+    et: f64,
+    frame: &str,
+    abcorr: impl Into<Aberration>,
+    obs: i32,
+) -> ([f64; 6], f64, f64) {
+    let frame = CString::new(frame).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let mut starg = [0.0; 6];
+    let mut lt = 0.0;
+    let mut dlt = 0.0;
+    unsafe {
+        crate::c::spkacs_c(
+            targ,
+            et,
+            frame.as_ptr() as *mut _,
+            abcorr,
+            obs,
+            starg.as_mut_ptr(),
+            &mut lt,
+            &mut dlt,
+        )
+    };
+    (starg, lt, dlt)
+}
+
+cspice_proc! {
+    /**
+    Translate a surface ID code, together with a body ID code, to the corresponding surface name.
+    If no such name exists, return a string representation of the surface ID code.
+
+    This function has a [neat version][crate::neat::srfc2s].
+    */
+    pub fn srfc2s(code: i32, bodyid: i32, srflen: i32) -> (String, bool) {}
+}
+
 cspice_proc! {
     /**
-    Return the position of a target body relative to an observing body, optionally corrected for
-    light time (planetary aberration) and stellar aberration.
+    Translate a surface ID code, together with a body string, to the corresponding surface name.
+    If no such surface name exists, return a string representation of the surface ID code.
+
+    This function has a [neat version][crate::neat::srfcss].
+    */
+    pub fn srfcss(code: i32, bodstr: &str, srflen: i32) -> (String, bool) {}
+}
+
+/**
+Map array of surface points on a specified target body to the corresponding unit length outward
+surface normal vectors.
+
+The surface of the target body may be represented by a triaxial ellipsoid or by topographic data
+provided by DSK files.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn srfnrm(
+    method: impl Into<ShapeModel>,
+    target: &str,
+    et: f64,
+    fixref: &str,
+    srfpts: &[[f64; 3]],
+) -> Vec<[f64; 3]> {
+    let method = CString::new(method.into().to_string()).unwrap();
+    let target = CString::new(target).unwrap();
+    let fixref = CString::new(fixref).unwrap();
+    let mut normls = vec![[0.0; 3]; srfpts.len()];
+    unsafe {
+        crate::c::srfnrm_c(
+            method.as_ptr() as *mut _,
+            target.as_ptr() as *mut _,
+            et,
+            fixref.as_ptr() as *mut _,
+            srfpts.len() as _,
+            srfpts.as_ptr() as *mut _,
+            normls.as_mut_ptr(),
+        )
+    };
+    normls
+}
+
+cspice_proc! {
+    /**
+    Translate a surface string, together with a body string, to the corresponding surface ID code.
+    The input strings may contain names or integer ID codes.
     */
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn spkpos(targ: &str, et: f64, frame: &str, abcorr: &str, obs: &str) -> ([f64; 3], f64) {}
+    pub fn srfs2c(srfstr: &str, bodstr: &str) -> (i32, bool) {}
 }
 
 cspice_proc! {
     /**
-    Return the state (position and velocity) of a target body
-    relative to an observing body, optionally corrected for light
-    time (planetary aberration) and stellar aberration.
+    Translate a surface string, together with a body ID code, to the corresponding surface ID
+    code. The input surface string may contain a name or an integer ID code.
     */
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn spkezr(targ: &str, et: f64, frame: &str, abcorr: &str, obs: &str) -> ([f64; 6], f64) {}
+    pub fn srfscc(srfstr: &str, bodyid: i32) -> (i32, bool) {}
 }
 
 cspice_proc! {
@@ -674,29 +2164,29 @@ ellipsoid or by topographic data provided by DSK files.
 */
 #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
 pub fn subpnt(
-    method: &str,
+    method: impl Into<SubPointMethod>,
     target: &str,
     et: f64,
     fixref: &str,
-    abcorr: &str,
+    abcorr: impl Into<Aberration>,
     obsrvr: &str,
 ) -> ([f64; 3], f64, [f64; 3]) {
-    let method = cstr!(method);
-    let target = cstr!(target);
-    let fixref = cstr!(fixref);
-    let abcorr = cstr!(abcorr);
-    let obsrvr = cstr!(obsrvr);
+    let method = CString::new(method.into().to_string()).unwrap();
+    let target = CString::new(target).unwrap();
+    let fixref = CString::new(fixref).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let obsrvr = CString::new(obsrvr).unwrap();
     let mut sp = [0.0; 3];
     let mut et_sp = 0.0;
     let mut vec_sp = [0.0; 3];
     unsafe {
         crate::c::subpnt_c(
-            method,
-            target,
+            method.as_ptr() as *mut _,
+            target.as_ptr() as *mut _,
             et,
-            fixref,
+            fixref.as_ptr() as *mut _,
             abcorr,
-            obsrvr,
+            obsrvr.as_ptr() as *mut _,
             &mut sp as _,
             &mut et_sp,
             &mut vec_sp as _,
@@ -705,6 +2195,55 @@ pub fn subpnt(
     (sp, et_sp, vec_sp)
 }
 
+/**
+Compute the rectangular coordinates of the sub-solar point on a target body at a specified epoch,
+optionally corrected for light time and stellar aberration.
+
+The surface of the target body may be represented by a triaxial ellipsoid or by topographic data
+provided by DSK files.
+
+This function has a [neat version][crate::neat::subslr].
+*/
+pub fn subslr(
+    method: impl Into<SubPointMethod>,
+    target: &str,
+    et: f64,
+    fixref: &str,
+    abcorr: impl Into<Aberration>,
+    obsrvr: &str,
+) -> ([f64; 3], f64, [f64; 3]) {
+    let method = CString::new(method.into().to_string()).unwrap();
+    let target = CString::new(target).unwrap();
+    let fixref = CString::new(fixref).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let obsrvr = CString::new(obsrvr).unwrap();
+    let mut spoint = [0.0; 3];
+    let mut trgepc = 0.0;
+    let mut srfvec = [0.0; 3];
+    unsafe {
+        crate::c::subslr_c(
+            method.as_ptr() as *mut _,
+            target.as_ptr() as *mut _,
+            et,
+            fixref.as_ptr() as *mut _,
+            abcorr,
+            obsrvr.as_ptr() as *mut _,
+            spoint.as_mut_ptr(),
+            &mut trgepc,
+            srfvec.as_mut_ptr(),
+        )
+    };
+    (spoint, trgepc, srfvec)
+}
+
+cspice_proc! {
+    /**
+    Compute the outward-pointing, unit normal vector at a point on the surface of an ellipsoid.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn surfnm(a: f64, b: f64, c: f64, point: [f64; 3]) -> [f64; 3] {}
+}
+
 cspice_proc! {
     /**
     Determine the intersection of a line-of-sight vector with the surface of an ellipsoid.
@@ -713,6 +2252,79 @@ cspice_proc! {
     pub fn surfpt(positn: [f64; 3], u: [f64; 3], a: f64, b: f64, c: f64) -> ([f64; 3], bool) {}
 }
 
+/**
+Find terminator points on a target body. The terminator is the set of points of tangency on
+the target body of planes tangent to both this body and to a light source.
+
+The method starts with the shadow whose boundary is sought, `UMBRAL` or `PENUMBRAL`, followed
+by the kind of points and the shape, as in [`limbpt`]. The cuts and the search are also set as in
+[`limbpt`], except that the half-planes contain the axis from the target center to the
+illumination source `ilusrc`. The aberration corrections apply to the target center or to each
+point of the terminator of the reference ellipsoid, with `corloc` being `CENTER` or
+`ELLIPSOID TERMINATOR`.
+
+Return the number of points found in each cut, the points, their epochs and the vectors from the
+observer to them.
+
+This function has a [neat version][crate::neat::termpt].
+*/
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn termpt(
+    method: &str,
+    ilusrc: &str,
+    target: &str,
+    et: f64,
+    fixref: &str,
+    abcorr: impl Into<Aberration>,
+    corloc: &str,
+    obsrvr: &str,
+    mut refvec: [f64; 3],
+    rolstp: f64,
+    ncuts: usize,
+    schstp: f64,
+    soltol: f64,
+    maxn: usize,
+) -> (Vec<i32>, Vec<[f64; 3]>, Vec<f64>, Vec<[f64; 3]>) {
+    let method = CString::new(method).unwrap();
+    let ilusrc = CString::new(ilusrc).unwrap();
+    let target = CString::new(target).unwrap();
+    let fixref = CString::new(fixref).unwrap();
+    let abcorr = abcorr.into().as_cstr().as_ptr() as *mut _;
+    let corloc = CString::new(corloc).unwrap();
+    let obsrvr = CString::new(obsrvr).unwrap();
+    let mut npts = vec![0; ncuts];
+    let mut points = vec![[0.0; 3]; maxn];
+    let mut epochs = vec![0.0; maxn];
+    let mut tangts = vec![[0.0; 3]; maxn];
+    unsafe {
+        crate::c::termpt_c(
+            method.as_ptr() as *mut _,
+            ilusrc.as_ptr() as *mut _,
+            target.as_ptr() as *mut _,
+            et,
+            fixref.as_ptr() as *mut _,
+            abcorr,
+            corloc.as_ptr() as *mut _,
+            obsrvr.as_ptr() as *mut _,
+            refvec.as_mut_ptr(),
+            rolstp,
+            ncuts as _,
+            schstp,
+            soltol,
+            maxn as _,
+            npts.as_mut_ptr(),
+            points.as_mut_ptr(),
+            epochs.as_mut_ptr(),
+            tangts.as_mut_ptr(),
+        )
+    };
+    let total = npts.iter().sum::<i32>().max(0) as usize;
+    points.truncate(total);
+    epochs.truncate(total);
+    tangts.truncate(total);
+    (npts, points, epochs, tangts)
+}
+
 /**
 Convert an input epoch represented in TDB seconds past the TDB epoch of J2000 to a character string formatted to the
 specifications of a user's format picture.
@@ -728,6 +2340,31 @@ pub fn timout(et: f64, pictur: &str, lenout: usize) -> String {
     fcstr!(varout_0)
 }
 
+/**
+Create a time format picture suitable for use by [`timout`] from a given sample time string.
+
+Return the picture, whether it could be built, and the error message otherwise.
+
+This function has a [neat version][crate::neat::tpictr].
+*/
+pub fn tpictr(sample: &str, lenout: usize, lenerr: usize) -> (String, bool, String) {
+    let sample = CString::new(sample).unwrap();
+    let pictur = mallocstr!(lenout);
+    let error = mallocstr!(lenerr);
+    let mut ok = 0;
+    unsafe {
+        crate::c::tpictr_c(
+            sample.as_ptr() as *mut _,
+            lenout as _,
+            lenerr as _,
+            pictur,
+            &mut ok,
+            error,
+        );
+    }
+    (fcstr!(pictur), ok != 0, fcstr!(error))
+}
+
 /**
 Transform time from one uniform scale to another. The uniform time scales are
 TAI, GPS, TT, TDT, TDB, ET, JED, JDTDB, JDTDT.
@@ -774,6 +2411,32 @@ cspice_proc! {
     pub fn vcrss(v1: [f64; 3], v2: [f64; 3]) -> [f64; 3] {}
 }
 
+/**
+Transform a state between coordinate systems.
+
+The systems are `"RECTANGULAR"`, `"CYLINDRICAL"`, `"LATITUDINAL"`, `"SPHERICAL"`, `"GEODETIC"` and
+`"PLANETOGRAPHIC"`. The body is only used by the last two, to read its radii and its rotation from
+the kernel pool, and can be left blank otherwise.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn xfmsta(istate: [f64; 6], icosys: &str, ocosys: &str, body: &str) -> [f64; 6] {
+    let mut istate = istate;
+    let icosys = CString::new(icosys).unwrap();
+    let ocosys = CString::new(ocosys).unwrap();
+    let body = CString::new(body).unwrap();
+    let mut ostate = [0.0; 6];
+    unsafe {
+        crate::c::xfmsta_c(
+            istate.as_mut_ptr(),
+            icosys.as_ptr() as *mut _,
+            ocosys.as_ptr() as *mut _,
+            body.as_ptr() as *mut _,
+            ostate.as_mut_ptr(),
+        )
+    };
+    ostate
+}
+
 cspice_proc! {
     /**
     Transpose a 3x3 matrix.
@@ -0,0 +1,459 @@
+/*!
+Synthetic images of a body seen by an instrument, and the maps of their geometry.
+*/
+
+use crate::core::catch;
+use crate::math::{unorm, vcrss, vdot, vhat, vlcom3, vminus, vnorm, vperp, vscl, vsub, Vector};
+use crate::{raw, Aberration, Error, ShapeModel, MAX_LEN_OUT};
+use std::fs;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Largest number of boundary vectors of a field of view.
+const MAX_BOUNDS: usize = 100;
+
+/**
+Reflectance law giving the brightness of the surface from the cosines of the incidence and
+emission angles.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Reflectance {
+    /// Lambert law, `cos i`.
+    #[default]
+    Lambert,
+    /// Lommel-Seeliger law, `cos i / (cos i + cos e)`, suited to dark bodies.
+    LommelSeeliger,
+}
+
+impl Reflectance {
+    /// Brightness of a lit surface seen under the incidence `i` and the emission `e`.
+    pub fn brightness(&self, incidence: f64, emission: f64) -> f64 {
+        let (mu0, mu) = (incidence.cos().max(0.0), emission.cos().max(0.0));
+        match self {
+            Self::Lambert => mu0,
+            Self::LommelSeeliger if mu0 + mu > 0.0 => mu0 / (mu0 + mu),
+            Self::LommelSeeliger => 0.0,
+        }
+    }
+}
+
+/**
+Format of the files written by [`PixelMap::save`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    /// Values as little-endian 64-bit floats, row by row, without header.
+    Raw,
+    /// Binary 8-bit portable graymap.
+    Pgm,
+    /// 8-bit grayscale PNG.
+    Png,
+}
+
+/**
+Map of a quantity over the pixels of an image, stored row by row, NaN where the pixel does not
+see the body.
+*/
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PixelMap {
+    /// Number of columns.
+    pub width: usize,
+    /// Number of rows.
+    pub height: usize,
+    /// Values, the one of the pixel at `row` and `column` being at `row * width + column`.
+    pub values: Vec<f64>,
+}
+
+impl PixelMap {
+    /// Map filled with NaN.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            values: vec![f64::NAN; width * height],
+        }
+    }
+
+    /// Value of a pixel.
+    pub fn get(&self, row: usize, column: usize) -> f64 {
+        self.values[row * self.width + column]
+    }
+
+    /**
+    Gray levels of the pixels, the finite values being scaled from their minimum or zero, whichever
+    is lower, to their maximum. NaN values are black.
+    */
+    pub fn gray(&self) -> Vec<u8> {
+        let finite = self.values.iter().filter(|v| v.is_finite());
+        let min = finite.clone().fold(0.0, |min: f64, &v| min.min(v));
+        let max = finite.fold(min, |max: f64, &v| max.max(v));
+        let scale = match max > min {
+            true => 255.0 / (max - min),
+            false => 0.0,
+        };
+        self.values
+            .iter()
+            .map(|&v| match v.is_finite() {
+                true => ((v - min) * scale).round() as u8,
+                false => 0,
+            })
+            .collect()
+    }
+
+    /// Write the map in the given format.
+    pub fn save<P: AsRef<Path>>(&self, path: P, format: ImageFormat) -> Result<(), Error> {
+        let path = path.as_ref();
+        let error = |e: io::Error| Error::Io(format!("{}: {}", path.display(), e));
+        let mut writer = BufWriter::new(fs::File::create(path).map_err(error)?);
+        match format {
+            ImageFormat::Raw => self.write_raw(&mut writer),
+            ImageFormat::Pgm => self.write_pgm(&mut writer),
+            ImageFormat::Png => self.write_png(&mut writer),
+        }
+        .and_then(|_| writer.flush())
+        .map_err(error)
+    }
+
+    /// Write the values as little-endian 64-bit floats, row by row.
+    pub fn write_raw<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        for v in &self.values {
+            w.write_all(&v.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Write the gray levels of [`PixelMap::gray`] as a binary portable graymap.
+    pub fn write_pgm<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write!(w, "P5\n{} {}\n255\n", self.width, self.height)?;
+        w.write_all(&self.gray())
+    }
+
+    /**
+    Write the gray levels of [`PixelMap::gray`] as a PNG image.
+
+    The image data are stored in uncompressed deflate blocks, which every PNG reader accepts.
+    */
+    pub fn write_png<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let gray = self.gray();
+        // Each row starts with the byte of its filter, none here.
+        let mut scanlines = Vec::with_capacity((self.width + 1) * self.height);
+        for row in gray.chunks(self.width.max(1)).take(self.height) {
+            scanlines.push(0);
+            scanlines.extend_from_slice(row);
+        }
+
+        let mut header = vec![];
+        header.extend_from_slice(&(self.width as u32).to_be_bytes());
+        header.extend_from_slice(&(self.height as u32).to_be_bytes());
+        // Bit depth 8, grayscale, deflate, adaptive filtering, no interlace.
+        header.extend_from_slice(&[8, 0, 0, 0, 0]);
+
+        w.write_all(b"\x89PNG\r\n\x1a\n")?;
+        png_chunk(w, b"IHDR", &header)?;
+        png_chunk(w, b"IDAT", &zlib_stored(&scanlines))?;
+        png_chunk(w, b"IEND", &[])
+    }
+}
+
+/// Write a PNG chunk: its length, its type, its data and the CRC of its type and data.
+fn png_chunk<W: Write>(w: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    w.write_all(&(data.len() as u32).to_be_bytes())?;
+    w.write_all(kind)?;
+    w.write_all(data)?;
+    let crc = !crc32(crc32(!0, kind), data);
+    w.write_all(&crc.to_be_bytes())
+}
+
+/// Update a CRC-32 with the bytes of `data`, without the final inversion.
+fn crc32(mut crc: u32, data: &[u8]) -> u32 {
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => 0xedb8_8320 ^ (crc >> 1),
+                _ => crc >> 1,
+            };
+        }
+    }
+    crc
+}
+
+/// Zlib stream of uncompressed deflate blocks holding `data`.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        stream.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none() as u8;
+        let len = block.len() as u16;
+        stream.push(last);
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    stream.extend_from_slice(&((b << 16) | a).to_be_bytes());
+    stream
+}
+
+/**
+Synthetic image of a body and the maps of its geometry, rendered by [`Renderer::render`].
+
+Every map has one value per pixel, NaN where the line of sight of the pixel misses the body.
+*/
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Rendering {
+    /// Name of the frame of the instrument.
+    pub frame: String,
+    /// Position of the observer relative to the target, in the body-fixed frame, in km.
+    pub position: Vector,
+    /// Matrix transforming vectors from the frame of the instrument to the body-fixed frame.
+    pub rotation: [[f64; 3]; 3],
+    /// Line of sight of each pixel, a unit vector in the frame of the instrument.
+    pub directions: Vec<Vector>,
+    /// Surface intercept of each pixel, in the body-fixed frame.
+    pub intercepts: Vec<Option<Vector>>,
+    /// 1-based ID of the plate seen by each pixel, for DSK shapes.
+    pub plates: Vec<Option<i32>>,
+    /// Distance from the observer to the intercept, in km.
+    pub range: PixelMap,
+    /// Incidence angle, in radians.
+    pub incidence: PixelMap,
+    /// Emission angle, in radians.
+    pub emission: PixelMap,
+    /// Phase angle, in radians.
+    pub phase: PixelMap,
+    /// Brightness given by the reflectance law, zero where the surface is in the shadow.
+    pub brightness: PixelMap,
+}
+
+/**
+Renderer of synthetic images of a body seen by an instrument.
+
+The line of sight of each pixel is built from the field of view given by [`raw::getfov`], and its
+intercept with the surface is found by [`raw::sincpt`]. The illumination angles at the intercept
+are given by [`raw::illumf`], which also tells whether the intercept is in the shadow. The plate
+seen by a pixel is found by [`raw::dskxsi`] when the shape is a DSK one. The position of the
+observer and the orientation of the instrument come from [`raw::spkpos`] and [`raw::pxform`].
+
+The image spans the bounding rectangle of the field of view, whose columns go along its first
+side (from its first to its second boundary vector) and whose rows go along the cross product of
+the boresight and that side. Circular and elliptical fields of view are rendered on their
+bounding rectangle, aligned with their first boundary vector.
+
+The shape is given by a [`ShapeModel`], whose DSK surfaces also restrict the search of the
+plates.
+
+```ignore
+let rendering = spice::Renderer::new("HERA_AFC-1", "DIMORPHOS", "DIMORPHOS_FIXED", "HERA")
+    .method(spice::ShapeModel::dsk(&[]))
+    .resolution(256, 256)
+    .reflectance(spice::Reflectance::LommelSeeliger)
+    .render(et)?;
+rendering.brightness.save("dimorphos.png", spice::ImageFormat::Png)?;
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Renderer {
+    instrument: String,
+    target: String,
+    fixref: String,
+    observer: String,
+    method: ShapeModel,
+    abcorr: Aberration,
+    illuminator: String,
+    width: usize,
+    height: usize,
+    reflectance: Reflectance,
+}
+
+impl Renderer {
+    /// Renderer of a target, in its body-fixed frame, seen by an instrument of an observer.
+    pub fn new(instrument: &str, target: &str, fixref: &str, observer: &str) -> Self {
+        Self {
+            instrument: instrument.to_string(),
+            target: target.to_string(),
+            fixref: fixref.to_string(),
+            observer: observer.to_string(),
+            method: ShapeModel::Ellipsoid,
+            abcorr: Aberration::CnS,
+            illuminator: "SUN".to_string(),
+            width: 512,
+            height: 512,
+            reflectance: Reflectance::default(),
+        }
+    }
+
+    /// Set the shape of the target, [`ShapeModel::Ellipsoid`] by default.
+    pub fn method(mut self, method: impl Into<ShapeModel>) -> Self {
+        self.method = method.into();
+        self
+    }
+
+    /// Set the aberration correction, converged Newtonian light time and stellar aberration by
+    /// default.
+    pub fn aberration(mut self, abcorr: impl Into<Aberration>) -> Self {
+        self.abcorr = abcorr.into();
+        self
+    }
+
+    /// Set the source of illumination, the Sun by default.
+    pub fn illuminator(mut self, illuminator: &str) -> Self {
+        self.illuminator = illuminator.to_string();
+        self
+    }
+
+    /// Set the number of columns and rows of the image, 512 by 512 by default.
+    pub fn resolution(mut self, width: usize, height: usize) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Set the reflectance law, Lambert by default.
+    pub fn reflectance(mut self, reflectance: Reflectance) -> Self {
+        self.reflectance = reflectance;
+        self
+    }
+
+    /// Render the image at an epoch, in TDB seconds past J2000.
+    pub fn render(&self, et: f64) -> Result<Rendering, Error> {
+        let (instid, found) = catch(|| raw::bodn2c(&self.instrument))?;
+        if !found {
+            return Err(Error::UnknownName(self.instrument.clone()));
+        }
+        let (shape, frame, bsight, bounds) =
+            catch(|| raw::getfov(instid as isize, MAX_BOUNDS, MAX_LEN_OUT, MAX_LEN_OUT))?;
+        let directions = self.directions(&shape, &bsight, &bounds);
+        catch(|| self.render_directions(et, frame, directions))
+    }
+
+    /**
+    Line of sight of each pixel, row by row, for a field of view given as by [`raw::getfov`].
+
+    The boundary vectors are projected on the plane at unit distance along the boresight, where
+    the pixels are evenly spaced. The single boundary vector of a `CIRCLE` gives the half-width of
+    a square, and the two of an `ELLIPSE` the half-widths along its axes.
+    */
+    pub fn directions(&self, shape: &str, bsight: &Vector, bounds: &[Vector]) -> Vec<Vector> {
+        let bsight = vhat(bsight);
+        let project = |v: &Vector| vperp(&vscl(1.0 / vdot(v, &bsight), v), &bsight);
+        let projected = bounds.iter().map(project).collect::<Vec<_>>();
+
+        let side = match projected.as_slice() {
+            [first, second, ..] if bounds.len() > 2 => vsub(second, first),
+            [first, ..] => *first,
+            [] => [0.0; 3],
+        };
+        let (u, _) = unorm(&vperp(&side, &bsight));
+        let v = vcrss(&bsight, &u);
+        let (mut half_u, mut half_v) = (0.0_f64, 0.0_f64);
+        for p in &projected {
+            half_u = half_u.max(vdot(p, &u).abs());
+            half_v = half_v.max(vdot(p, &v).abs());
+        }
+        if shape.trim().eq_ignore_ascii_case("CIRCLE") {
+            half_v = half_u;
+        }
+
+        let mut directions = Vec::with_capacity(self.width * self.height);
+        for row in 0..self.height {
+            let y = half_v * (2.0 * (row as f64 + 0.5) / self.height as f64 - 1.0);
+            for column in 0..self.width {
+                let x = half_u * (2.0 * (column as f64 + 0.5) / self.width as f64 - 1.0);
+                directions.push(vhat(&vlcom3(1.0, &bsight, x, &u, y, &v)));
+            }
+        }
+        directions
+    }
+
+    /// Trace the lines of sight and fill the maps.
+    fn render_directions(&self, et: f64, frame: String, directions: Vec<Vector>) -> Rendering {
+        let (target, _) = raw::spkpos(&self.target, et, &self.fixref, self.abcorr, &self.observer);
+
+        let mut rendering = Rendering {
+            position: vminus(&target),
+            rotation: raw::pxform(&frame, &self.fixref, et),
+            intercepts: vec![None; directions.len()],
+            plates: vec![None; directions.len()],
+            range: PixelMap::new(self.width, self.height),
+            incidence: PixelMap::new(self.width, self.height),
+            emission: PixelMap::new(self.width, self.height),
+            phase: PixelMap::new(self.width, self.height),
+            brightness: PixelMap::new(self.width, self.height),
+            ..Default::default()
+        };
+
+        // The plates of a raw DSK shape are searched among every surface.
+        let surfaces = match &self.method {
+            ShapeModel::Dsk { surfaces } => Some(surfaces.as_slice()),
+            shape if shape.is_dsk() => Some(&[][..]),
+            _ => None,
+        };
+
+        for (pixel, dvec) in directions.iter().enumerate() {
+            let (spoint, _, _, found) = raw::sincpt(
+                &self.method,
+                &self.target,
+                et,
+                &self.fixref,
+                self.abcorr,
+                &self.observer,
+                &frame,
+                *dvec,
+            );
+            if !found {
+                continue;
+            }
+
+            let (trgepc, srfvec, phase, incidence, emission, _, lit) = raw::illumf(
+                &self.method,
+                &self.target,
+                &self.illuminator,
+                et,
+                &self.fixref,
+                self.abcorr,
+                &self.observer,
+                spoint,
+            );
+            rendering.intercepts[pixel] = Some(spoint);
+            rendering.range.values[pixel] = vnorm(&srfvec);
+            rendering.incidence.values[pixel] = incidence;
+            rendering.emission.values[pixel] = emission;
+            rendering.phase.values[pixel] = phase;
+            rendering.brightness.values[pixel] = match lit {
+                true => self.reflectance.brightness(incidence, emission),
+                false => 0.0,
+            };
+
+            if let Some(surfaces) = surfaces {
+                // The line of sight is traced again in the body-fixed frame, ending beyond the
+                // intercept, to find its plate.
+                let vertex = vsub(&spoint, &srfvec);
+                let (_, _, _, _, _, ic, found) = raw::dskxsi(
+                    false,
+                    &self.target,
+                    surfaces,
+                    trgepc,
+                    &self.fixref,
+                    vertex,
+                    srfvec,
+                );
+                if found {
+                    rendering.plates[pixel] = Some(ic[0]);
+                }
+            }
+        }
+
+        rendering.frame = frame;
+        rendering.directions = directions;
+        rendering
+    }
+}
@@ -0,0 +1,90 @@
+/*!
+Surfaces of DSK shape models, named in the context of their body.
+*/
+
+use crate::{neat, raw, Body, ShapeModel};
+use std::fmt;
+
+/**
+A surface of a DSK shape model, identified by its surface ID and its body.
+
+Surface IDs are only unique for a given body, so that the name/ID translation, done with
+[`raw::srfscc`] and [`neat::srfc2s`], needs the body. A surface does not need a name to be used:
+the surfaces of a DSK file are selected by their IDs.
+
+The surfaces select the DSK data of the geometry functions such as [`raw::sincpt`] or
+[`raw::illumf`] through [`ShapeModel::dsk`].
+
+```ignore
+let dimorphos = spice::Body::from_name("DIMORPHOS").unwrap();
+let low = spice::Surface::from_name("DIMORPHOS_LOW_RES", dimorphos).unwrap();
+let shape = spice::ShapeModel::dsk(&[low]);
+let (spoint, trgepc, srfvec, found) =
+    spice::sincpt(&shape, "DIMORPHOS", et, "DIMORPHOS_FIXED", "NONE", "HERA", "J2000", dvec);
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Surface {
+    id: i32,
+    body: Body,
+}
+
+impl Surface {
+    /// Surface of a body from its ID, which does not need to be associated with a name.
+    pub fn new(id: i32, body: Body) -> Self {
+        Self { id, body }
+    }
+
+    /// Surface of a body from its name, or from its ID written as a string, if it is known to
+    /// SPICE.
+    pub fn from_name(name: &str, body: Body) -> Option<Self> {
+        match raw::srfscc(name, body.id()) {
+            (id, true) => Some(Self { id, body }),
+            _ => None,
+        }
+    }
+
+    /// Surface ID.
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    /// Body of the surface.
+    pub fn body(&self) -> Body {
+        self.body
+    }
+
+    /// Name associated with the surface.
+    pub fn name(&self) -> Option<String> {
+        match neat::srfc2s(self.id, self.body.id()) {
+            (name, true) => Some(name),
+            _ => None,
+        }
+    }
+
+    /**
+    `METHOD` string selecting the DSK data of the given surfaces, or of every surface if none is
+    given, such as `DSK/UNPRIORITIZED/SURFACES = 1, 2`.
+
+    The surfaces are written with their IDs, which are unambiguous. This is the string of
+    [`ShapeModel::dsk`].
+    */
+    pub fn dsk_method(surfaces: &[Surface]) -> String {
+        ShapeModel::dsk(surfaces).to_string()
+    }
+}
+
+impl From<Surface> for i32 {
+    fn from(surface: Surface) -> Self {
+        surface.id
+    }
+}
+
+impl fmt::Display for Surface {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "{}", self.id),
+        }
+    }
+}
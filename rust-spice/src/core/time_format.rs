@@ -0,0 +1,317 @@
+/*!
+Output formats of epochs, for [`crate::neat::timout`] and [`crate::neat::et2utc`].
+*/
+
+use crate::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/**
+Style of the month in a calendar date.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MonthStyle {
+    /// Two digits month number: `MM`.
+    Number,
+    /// Three letters abbreviation in upper case: `MON`.
+    Abbreviated,
+    /// Three letters abbreviation with a capital letter: `Mon`.
+    AbbreviatedTitle,
+    /// Three letters abbreviation in lower case: `mon`.
+    AbbreviatedLower,
+    /// Full name in upper case: `MONTH`.
+    Full,
+    /// Full name with a capital letter: `Month`.
+    FullTitle,
+    /// Full name in lower case: `month`.
+    FullLower,
+}
+
+impl MonthStyle {
+    /// Token of the picture.
+    fn token(&self) -> &'static str {
+        match self {
+            Self::Number => "MM",
+            Self::Abbreviated => "MON",
+            Self::AbbreviatedTitle => "Mon",
+            Self::AbbreviatedLower => "mon",
+            Self::Full => "MONTH",
+            Self::FullTitle => "Month",
+            Self::FullLower => "month",
+        }
+    }
+}
+
+/**
+How the date is written.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateStyle {
+    /// Year, month and day of month.
+    Calendar(MonthStyle),
+    /// Year and day of year.
+    DayOfYear,
+    /// Julian date.
+    JulianDate,
+}
+
+/**
+Time system in which the epoch is written.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeSystem {
+    /// Coordinated Universal Time, the default.
+    Utc,
+    /// Barycentric Dynamical Time.
+    Tdb,
+    /// Terrestrial Dynamical Time.
+    Tdt,
+}
+
+impl TimeSystem {
+    /// Token of the picture.
+    fn token(&self) -> &'static str {
+        match self {
+            Self::Utc => "::UTC",
+            Self::Tdb => "::TDB",
+            Self::Tdt => "::TDT",
+        }
+    }
+}
+
+/**
+How the last written digit is obtained.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rounding {
+    /// Truncate the remaining digits, the default of SPICE.
+    #[default]
+    Truncate,
+    /// Round to the closest value.
+    Round,
+}
+
+/**
+Builder of a format picture for [`crate::neat::timout`].
+
+It only renders valid pictures from a subset of the tokens described in the
+[C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/timout_c.html). Use
+[`crate::neat::tpictr`] to get a picture from an example string instead.
+
+```
+use spice::{Rounding, TimeFormat, TimeSystem};
+
+let format = TimeFormat::iso_calendar()
+    .fraction_digits(3)
+    .time_system(TimeSystem::Tdb)
+    .rounding(Rounding::Round);
+
+assert_eq!(format.picture(), "YYYY-MM-DDTHR:MN:SC.### ::TDB ::RND");
+assert_eq!(TimeFormat::default().picture(), spice::TIME_FORMAT);
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeFormat {
+    date: DateStyle,
+    iso: bool,
+    two_digit_year: bool,
+    time: bool,
+    fraction_digits: usize,
+    time_system: Option<TimeSystem>,
+    rounding: Rounding,
+}
+
+impl TimeFormat {
+    fn new(date: DateStyle, iso: bool) -> Self {
+        Self {
+            date,
+            iso,
+            two_digit_year: false,
+            time: true,
+            fraction_digits: 0,
+            time_system: None,
+            rounding: Rounding::default(),
+        }
+    }
+
+    /// Calendar date and time: `YYYY-MON-DD HR:MN:SC`.
+    pub fn calendar() -> Self {
+        Self::new(DateStyle::Calendar(MonthStyle::Abbreviated), false)
+    }
+
+    /// Day of year and time: `YYYY-DOY // HR:MN:SC`.
+    pub fn day_of_year() -> Self {
+        Self::new(DateStyle::DayOfYear, false)
+    }
+
+    /// Julian date: `JD JULIAND`.
+    pub fn julian_date() -> Self {
+        Self::new(DateStyle::JulianDate, false)
+    }
+
+    /// ISO calendar date and time: `YYYY-MM-DDTHR:MN:SC`.
+    pub fn iso_calendar() -> Self {
+        Self::new(DateStyle::Calendar(MonthStyle::Number), true)
+    }
+
+    /// ISO day of year and time: `YYYY-DOYTHR:MN:SC`.
+    pub fn iso_day_of_year() -> Self {
+        Self::new(DateStyle::DayOfYear, true)
+    }
+
+    /// Style of the month, for calendar dates only.
+    pub fn month(mut self, style: MonthStyle) -> Self {
+        if let DateStyle::Calendar(_) = self.date {
+            self.date = DateStyle::Calendar(style);
+        }
+        self
+    }
+
+    /// Write only the last two digits of the year.
+    pub fn two_digit_year(mut self) -> Self {
+        self.two_digit_year = true;
+        self
+    }
+
+    /// Write the date without the time of day.
+    pub fn date_only(mut self) -> Self {
+        self.time = false;
+        self
+    }
+
+    /// Number of digits after the seconds, or after the day for Julian dates.
+    pub fn fraction_digits(mut self, digits: usize) -> Self {
+        self.fraction_digits = digits;
+        self
+    }
+
+    /// Time system of the output.
+    pub fn time_system(mut self, system: TimeSystem) -> Self {
+        self.time_system = Some(system);
+        self
+    }
+
+    /// Truncation or rounding of the last digit.
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Picture to be given to [`crate::neat::timout`].
+    pub fn picture(&self) -> String {
+        let fraction = match self.fraction_digits {
+            0 => String::new(),
+            n => format!(".{}", "#".repeat(n)),
+        };
+        let year = match self.two_digit_year {
+            true => "YR",
+            false => "YYYY",
+        };
+
+        let mut picture = match self.date {
+            DateStyle::JulianDate => format!("JD JULIAND{}", fraction),
+            DateStyle::Calendar(month) => format!("{}-{}-DD", year, month.token()),
+            DateStyle::DayOfYear => format!("{}-DOY", year),
+        };
+
+        if self.time && self.date != DateStyle::JulianDate {
+            let separator = match (self.iso, self.date) {
+                (true, _) => "T",
+                (false, DateStyle::DayOfYear) => " // ",
+                (false, _) => " ",
+            };
+            picture.push_str(&format!("{}HR:MN:SC{}", separator, fraction));
+        }
+
+        if let Some(system) = self.time_system {
+            picture.push(' ');
+            picture.push_str(system.token());
+        }
+
+        if self.rounding == Rounding::Round {
+            picture.push_str(" ::RND");
+        }
+
+        picture
+    }
+}
+
+/// Same as [`crate::TIME_FORMAT`].
+impl Default for TimeFormat {
+    fn default() -> Self {
+        Self::calendar().rounding(Rounding::Round)
+    }
+}
+
+impl fmt::Display for TimeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.picture())
+    }
+}
+
+/**
+Output formats of [`crate::neat::et2utc`].
+
+For backward compatibility, the single letter strings of CSPICE are also accepted, see the
+[C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/et2utc_c.html).
+An unknown letter given to [`crate::neat::et2utc`] panics, [`str::parse`] tells it with
+[`Error::InvalidUtcFormat`] instead.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum UtcFormat {
+    /// `1986 APR 12 16:31:09.814`
+    #[default]
+    Calendar,
+    /// `1986-102 // 16:31:12.814`
+    DayOfYear,
+    /// `JD 2446533.18834276`
+    JulianDate,
+    /// `1987-04-12T16:31:12.814`
+    IsoCalendar,
+    /// `1987-102T16:31:12.814`
+    IsoDayOfYear,
+}
+
+impl UtcFormat {
+    /// All the output formats.
+    pub const ALL: [Self; 5] = [
+        Self::Calendar,
+        Self::DayOfYear,
+        Self::JulianDate,
+        Self::IsoCalendar,
+        Self::IsoDayOfYear,
+    ];
+
+    /// String understood by CSPICE.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Calendar => "C",
+            Self::DayOfYear => "D",
+            Self::JulianDate => "J",
+            Self::IsoCalendar => "ISOC",
+            Self::IsoDayOfYear => "ISOD",
+        }
+    }
+}
+
+impl fmt::Display for UtcFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for UtcFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let format = s.trim().to_uppercase();
+
+        Self::ALL
+            .iter()
+            .find(|f| f.as_str() == format)
+            .copied()
+            .ok_or_else(|| Error::InvalidUtcFormat(s.to_string()))
+    }
+}
+
+impl_from_str!(UtcFormat);
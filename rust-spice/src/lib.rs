@@ -28,6 +28,8 @@ pub mod c {
     pub use cspice_sys::*;
 }
 
+pub mod math;
+
 // The unguarded API should only be exposed if the lock is disabled
 #[cfg(not(feature = "lock"))]
 pub mod core;
@@ -40,7 +42,13 @@ pub(crate) mod core;
 pub(crate) use crate::core::*;
 
 // These items need to be exposed regardless of whether 'lock' is enabled or not
-pub use crate::core::{DLADSC, DSKDSC, MAX_LEN_OUT, TIME_FORMAT, TIME_FORMAT_SIZE};
+pub use crate::core::{
+    Aberration, Bvh, CoordinateSystem, CorrectionLocus, CurveType, Cut, Cuts, DataClass, DateStyle,
+    Dsk02Keyword, Ellipse, Error, Illumination, LeapSeconds, MeshFormat, MonthStyle, Plane,
+    PlateModel, RayHit, Rounding, Shadow, ShapeModel, SubPoint, SubPointMethod, TimeFormat,
+    TimeSystem, TriaxialEllipsoid, UtcFormat, DLADSC, DSKDSC, MAX_LEN_OUT, TIME_FORMAT,
+    TIME_FORMAT_SIZE,
+};
 
 #[cfg(any(feature = "lock", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "lock")))]
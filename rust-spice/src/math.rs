@@ -0,0 +1,323 @@
+/*!
+Vector and matrix routines of SPICE, written in Rust.
+
+## Description
+
+These functions follow the algorithms of their CSPICE counterparts, including the scalings which
+protect [`vnorm`], [`vproj`] or [`ucrss`] from overflows and the formula of [`vsep`] which stays
+accurate for nearly parallel vectors. They touch no global state: they are available whether the
+`lock` feature is enabled or not, and can be called from any thread.
+
+As in CSPICE, matrices are stored row by row: `m[i][j]` is the element at row `i` and column `j`.
+Axes are numbered from 1 to 3 in [`rotate`], [`rotmat`], [`rotvec`] and [`twovec`].
+*/
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// 3-dimensional vector.
+pub type Vector = [f64; 3];
+/// 3x3 matrix, stored row by row.
+pub type Matrix = [[f64; 3]; 3];
+
+/// Largest absolute value of the components of a vector.
+fn max_abs(v: &Vector) -> f64 {
+    v[0].abs().max(v[1].abs()).max(v[2].abs())
+}
+
+/// Sum of two vectors.
+pub fn vadd(v1: &Vector, v2: &Vector) -> Vector {
+    [v1[0] + v2[0], v1[1] + v2[1], v1[2] + v2[2]]
+}
+
+/// Difference of two vectors.
+pub fn vsub(v1: &Vector, v2: &Vector) -> Vector {
+    [v1[0] - v2[0], v1[1] - v2[1], v1[2] - v2[2]]
+}
+
+/// Product of a scalar and a vector.
+pub fn vscl(s: f64, v: &Vector) -> Vector {
+    [s * v[0], s * v[1], s * v[2]]
+}
+
+/// Negation of a vector.
+pub fn vminus(v: &Vector) -> Vector {
+    [-v[0], -v[1], -v[2]]
+}
+
+/// Whether a vector is zero.
+pub fn vzero(v: &Vector) -> bool {
+    v[0] == 0.0 && v[1] == 0.0 && v[2] == 0.0
+}
+
+/// Linear combination `a v1 + b v2`.
+pub fn vlcom(a: f64, v1: &Vector, b: f64, v2: &Vector) -> Vector {
+    [
+        a * v1[0] + b * v2[0],
+        a * v1[1] + b * v2[1],
+        a * v1[2] + b * v2[2],
+    ]
+}
+
+/// Linear combination `a v1 + b v2 + c v3`.
+pub fn vlcom3(a: f64, v1: &Vector, b: f64, v2: &Vector, c: f64, v3: &Vector) -> Vector {
+    [
+        a * v1[0] + b * v2[0] + c * v3[0],
+        a * v1[1] + b * v2[1] + c * v3[1],
+        a * v1[2] + b * v2[2] + c * v3[2],
+    ]
+}
+
+/// Dot product of two vectors.
+pub fn vdot(v1: &Vector, v2: &Vector) -> f64 {
+    v1[0] * v2[0] + v1[1] * v2[1] + v1[2] * v2[2]
+}
+
+/// Cross product of two vectors.
+pub fn vcrss(v1: &Vector, v2: &Vector) -> Vector {
+    [
+        v1[1] * v2[2] - v1[2] * v2[1],
+        v1[2] * v2[0] - v1[0] * v2[2],
+        v1[0] * v2[1] - v1[1] * v2[0],
+    ]
+}
+
+/// Magnitude of a vector, scaled by its largest component to avoid overflows.
+pub fn vnorm(v: &Vector) -> f64 {
+    let vmax = max_abs(v);
+    if vmax == 0.0 {
+        return 0.0;
+    }
+    let [x, y, z] = [v[0] / vmax, v[1] / vmax, v[2] / vmax];
+    vmax * (x * x + y * y + z * z).sqrt()
+}
+
+/// Distance between two points.
+pub fn vdist(v1: &Vector, v2: &Vector) -> f64 {
+    vnorm(&vsub(v1, v2))
+}
+
+/// Unit vector along a vector, and its magnitude. The zero vector is returned as is.
+pub fn unorm(v: &Vector) -> (Vector, f64) {
+    let vmag = vnorm(v);
+    match vmag > 0.0 {
+        true => ([v[0] / vmag, v[1] / vmag, v[2] / vmag], vmag),
+        false => ([0.0; 3], 0.0),
+    }
+}
+
+/// Unit vector along a vector. The zero vector is returned as is.
+pub fn vhat(v: &Vector) -> Vector {
+    unorm(v).0
+}
+
+/// Unit vector along the cross product of two vectors, or zero if they are parallel.
+pub fn ucrss(v1: &Vector, v2: &Vector) -> Vector {
+    let scaled = |v: &Vector| match max_abs(v) {
+        vmax if vmax != 0.0 => [v[0] / vmax, v[1] / vmax, v[2] / vmax],
+        _ => [0.0; 3],
+    };
+    vhat(&vcrss(&scaled(v1), &scaled(v2)))
+}
+
+/**
+Angle between two vectors in [0, pi], zero if any of them is zero.
+
+The angle is computed from the distance between the unit vectors rather than from their dot
+product, which loses the accuracy for nearly parallel or opposite vectors.
+*/
+pub fn vsep(v1: &Vector, v2: &Vector) -> f64 {
+    let (u1, dmag1) = unorm(v1);
+    if dmag1 == 0.0 {
+        return 0.0;
+    }
+    let (u2, dmag2) = unorm(v2);
+    if dmag2 == 0.0 {
+        return 0.0;
+    }
+
+    let dot = vdot(&u1, &u2);
+    if dot > 0.0 {
+        2.0 * (0.5 * vnorm(&vsub(&u1, &u2))).asin()
+    } else if dot < 0.0 {
+        PI - 2.0 * (0.5 * vnorm(&vadd(&u1, &u2))).asin()
+    } else {
+        FRAC_PI_2
+    }
+}
+
+/// Projection of `a` onto `b`, zero if any of them is zero.
+pub fn vproj(a: &Vector, b: &Vector) -> Vector {
+    let biga = max_abs(a);
+    let bigb = max_abs(b);
+    if biga == 0.0 || bigb == 0.0 {
+        return [0.0; 3];
+    }
+
+    let r = [b[0] / bigb, b[1] / bigb, b[2] / bigb];
+    let t = [a[0] / biga, a[1] / biga, a[2] / biga];
+    let scale = vdot(&t, &r) * biga / vdot(&r, &r);
+    vscl(scale, &r)
+}
+
+/// Component of `a` orthogonal to `b`, `a` itself if `b` is zero.
+pub fn vperp(a: &Vector, b: &Vector) -> Vector {
+    let biga = max_abs(a);
+    if biga == 0.0 {
+        return [0.0; 3];
+    }
+
+    let t = [a[0] / biga, a[1] / biga, a[2] / biga];
+    let p = vproj(&t, b);
+    vscl(biga, &vsub(&t, &p))
+}
+
+/// Rotation of a vector by `theta` radians about `axis`, `v` itself if `axis` is zero.
+pub fn vrotv(v: &Vector, axis: &Vector, theta: f64) -> Vector {
+    if vzero(axis) {
+        return *v;
+    }
+
+    let x = vhat(axis);
+    let p = vproj(v, &x);
+    let v1 = vsub(v, &p);
+    let v2 = vcrss(&x, &v1);
+    let rplane = vlcom(theta.cos(), &v1, theta.sin(), &v2);
+    vadd(&rplane, &p)
+}
+
+/// Identity matrix.
+pub fn ident() -> Matrix {
+    [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+}
+
+/// Transpose of a matrix.
+pub fn xpose(m: &Matrix) -> Matrix {
+    let mut mout = [[0.0; 3]; 3];
+    for (i, row) in mout.iter_mut().enumerate() {
+        for (j, element) in row.iter_mut().enumerate() {
+            *element = m[j][i];
+        }
+    }
+    mout
+}
+
+/// Product of a matrix and a vector.
+pub fn mxv(m: &Matrix, v: &Vector) -> Vector {
+    [vdot(&m[0], v), vdot(&m[1], v), vdot(&m[2], v)]
+}
+
+/// Product of the transpose of a matrix and a vector.
+pub fn mtxv(m: &Matrix, v: &Vector) -> Vector {
+    mxv(&xpose(m), v)
+}
+
+/// Product of two matrices.
+pub fn mxm(m1: &Matrix, m2: &Matrix) -> Matrix {
+    let mut mout = [[0.0; 3]; 3];
+    for (i, row) in mout.iter_mut().enumerate() {
+        for (j, element) in row.iter_mut().enumerate() {
+            *element = m1[i][0] * m2[0][j] + m1[i][1] * m2[1][j] + m1[i][2] * m2[2][j];
+        }
+    }
+    mout
+}
+
+/// Product of the transpose of the first matrix and the second one.
+pub fn mtxm(m1: &Matrix, m2: &Matrix) -> Matrix {
+    mxm(&xpose(m1), m2)
+}
+
+/// Product of the first matrix and the transpose of the second one.
+pub fn mxmt(m1: &Matrix, m2: &Matrix) -> Matrix {
+    mxm(m1, &xpose(m2))
+}
+
+/// Scalar `v1^T m v2`.
+pub fn vtmv(v1: &Vector, m: &Matrix, v2: &Vector) -> f64 {
+    vdot(v1, &mxv(m, v2))
+}
+
+/// Determinant of a matrix.
+pub fn det(m: &Matrix) -> f64 {
+    m[0][0] * (m[1][1] * m[2][2] - m[2][1] * m[1][2])
+        - m[0][1] * (m[1][0] * m[2][2] - m[2][0] * m[1][2])
+        + m[0][2] * (m[1][0] * m[2][1] - m[2][0] * m[1][1])
+}
+
+/// Indices of the rotation axis, of the next one and of the last one, from an axis in 1..=3.
+fn axes(iaxis: i32) -> (usize, usize, usize) {
+    let i1 = (iaxis - 1).rem_euclid(3) as usize;
+    (i1, (i1 + 1) % 3, (i1 + 2) % 3)
+}
+
+/**
+Matrix of the rotation of frames by `angle` radians about the axis `iaxis`.
+
+Applied to a vector, it gives its coordinates in the rotated frame: this is the rotation of the
+vector by `-angle`.
+*/
+pub fn rotate(angle: f64, iaxis: i32) -> Matrix {
+    let (s, c) = angle.sin_cos();
+    let (i1, i2, i3) = axes(iaxis);
+
+    let mut mout = [[0.0; 3]; 3];
+    mout[i1][i1] = 1.0;
+    mout[i2][i2] = c;
+    mout[i2][i3] = s;
+    mout[i3][i2] = -s;
+    mout[i3][i3] = c;
+    mout
+}
+
+/// Product `[angle]_iaxis m` of the frame rotation of [`rotate`] and a matrix.
+pub fn rotmat(m: &Matrix, angle: f64, iaxis: i32) -> Matrix {
+    let (s, c) = angle.sin_cos();
+    let (i1, i2, i3) = axes(iaxis);
+
+    let mut mout = [[0.0; 3]; 3];
+    for j in 0..3 {
+        mout[i1][j] = m[i1][j];
+        mout[i2][j] = c * m[i2][j] + s * m[i3][j];
+        mout[i3][j] = -s * m[i2][j] + c * m[i3][j];
+    }
+    mout
+}
+
+/// Product `[angle]_iaxis v` of the frame rotation of [`rotate`] and a vector.
+pub fn rotvec(v: &Vector, angle: f64, iaxis: i32) -> Vector {
+    let (s, c) = angle.sin_cos();
+    let (i1, i2, i3) = axes(iaxis);
+
+    let mut vout = [0.0; 3];
+    vout[i1] = v[i1];
+    vout[i2] = c * v[i2] + s * v[i3];
+    vout[i3] = -s * v[i2] + c * v[i3];
+    vout
+}
+
+/**
+Matrix transforming vectors to a frame whose axis `indexa` is along `axdef`, and whose axis
+`indexp` is in the plane of `axdef` and `plndef`, on the side of `plndef`.
+
+Return `None` if the indices are not distinct axes in 1..=3, or if the vectors are parallel.
+*/
+pub fn twovec(axdef: &Vector, indexa: i32, plndef: &Vector, indexp: i32) -> Option<Matrix> {
+    if !(1..=3).contains(&indexa) || !(1..=3).contains(&indexp) || indexa == indexp {
+        return None;
+    }
+    if vzero(&vcrss(axdef, plndef)) {
+        return None;
+    }
+
+    let (i1, i2, i3) = axes(indexa);
+    let mut mout = [[0.0; 3]; 3];
+    mout[i1] = vhat(axdef);
+    if indexp as usize - 1 == i2 {
+        mout[i3] = ucrss(axdef, plndef);
+        mout[i2] = ucrss(&mout[i3], axdef);
+    } else {
+        mout[i2] = ucrss(plndef, axdef);
+        mout[i3] = ucrss(axdef, &mout[i2]);
+    }
+    Some(mout)
+}
@@ -1,5 +1,42 @@
 use itertools::multizip;
 
+#[test]
+fn aberration() {
+    use spice::Aberration;
+
+    for abcorr in Aberration::ALL.iter() {
+        assert_eq!(abcorr.to_string().parse::<Aberration>(), Ok(*abcorr));
+    }
+
+    assert_eq!("none".parse::<Aberration>(), Ok(Aberration::None));
+    assert_eq!(" Lt + s ".parse::<Aberration>(), Ok(Aberration::LtS));
+    assert_eq!(Aberration::from("XCN+S"), Aberration::XCnS);
+    assert!("LT+T".parse::<Aberration>().is_err());
+
+    assert!(Aberration::XLtS.is_transmission());
+    assert!(Aberration::CnS.has_stellar());
+    assert!(!Aberration::None.has_light_time());
+}
+
+#[test]
+#[serial]
+fn aberration_spkpos() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+    for (abcorr, typed) in [
+        ("NONE", spice::Aberration::None),
+        ("LT+S", spice::Aberration::LtS),
+    ] {
+        assert_eq!(
+            spice::spkpos("DIMORPHOS", et, "J2000", typed, "HERA"),
+            spice::spkpos("DIMORPHOS", et, "J2000", abcorr, "HERA")
+        );
+    }
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
 #[test]
 #[serial]
 fn das() {
@@ -19,6 +56,231 @@ fn das() {
     spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
 }
 
+#[test]
+fn dsk_codes() {
+    use spice::{CoordinateSystem, DataClass};
+
+    let pdt = CoordinateSystem::Planetodetic { re: 3.0, f: 0.1 };
+    for corsys in [
+        CoordinateSystem::Latitudinal,
+        CoordinateSystem::Cylindrical,
+        CoordinateSystem::Rectangular,
+        pdt,
+    ] {
+        assert_eq!(
+            CoordinateSystem::from_code(corsys.code(), &corsys.parameters()),
+            Some(corsys)
+        );
+    }
+    assert_eq!(pdt.code(), 4);
+    assert_eq!(pdt.parameters()[..3], [3.0, 0.1, 0.0]);
+    assert_eq!(CoordinateSystem::from_code(0, &[0.0; 10]), None);
+
+    assert_eq!(DataClass::Single.code(), 1);
+    assert_eq!(DataClass::from_code(2), Some(DataClass::General));
+    assert_eq!(DataClass::from_code(3), None);
+}
+
+#[test]
+#[serial]
+fn dsk_writer() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let handle = spice::dasopr("/Users/gregoireh/data/spice-kernels/hera/kernels/dsk/g_08438mm_lgt_obj_didb_0000n00000_v002.bds");
+    let (dladsc, _) = spice::dlabfs(handle);
+    let dskdsc = spice::dskgd(handle, dladsc);
+    let vertices = spice::dskv02(handle, dladsc);
+    let plates = spice::dskp02(handle, dladsc);
+    spice::dascls(handle);
+
+    let path = std::env::temp_dir().join("rust-spice-dsk-writer.bds");
+    let path = path.to_str().unwrap();
+    let _ = std::fs::remove_file(path);
+
+    let writer = spice::DskWriter::new(7, dskdsc.center, "DIMORPHOS_FIXED")
+        .data_class(spice::DataClass::General)
+        .time_bounds(0.0, 1e9);
+    writer.write(path, &vertices, &plates).unwrap();
+    assert!(writer.write(path, &vertices, &plates).is_err());
+
+    let handle = spice::dasopr(path);
+    let (dladsc, found) = spice::dlabfs(handle);
+    assert!(found);
+    let written = spice::dskgd(handle, dladsc);
+    assert_eq!(written.surfce, 7);
+    assert_eq!(written.center, dskdsc.center);
+    assert_eq!(written.dclass, 2);
+    assert_eq!(written.corsys, 1);
+    assert_eq!((written.start, written.stop), (0.0, 1e9));
+    assert_relative_eq!(written.co3max, dskdsc.co3max, max_relative = 1e-6);
+    assert_eq!(
+        spice::dskz02(handle, dladsc),
+        (vertices.len() as i32, plates.len() as i32)
+    );
+    assert_eq!(spice::dskp02(handle, dladsc), plates);
+    spice::dascls(handle);
+
+    std::fs::remove_file(path).unwrap();
+
+    let (mncor1, mxcor1, mncor2, mxcor2) = (0.1, 0.5, -0.2, 0.3);
+    writer
+        .clone()
+        .coordinate_bounds(mncor1, mxcor1, mncor2, mxcor2)
+        .write(path, &vertices, &plates)
+        .unwrap();
+    let handle = spice::dasopr(path);
+    let written = spice::dskgd(handle, spice::dlabfs(handle).0);
+    assert_eq!(
+        (
+            written.co1min,
+            written.co1max,
+            written.co2min,
+            written.co2max
+        ),
+        (mncor1, mxcor1, mncor2, mxcor2)
+    );
+    spice::dascls(handle);
+
+    std::fs::remove_file(path).unwrap();
+    spice::kclear();
+}
+
+#[test]
+fn dsk_writer_plates() {
+    let path = std::env::temp_dir().join("rust-spice-dsk-writer-plates.bds");
+    let path = path.to_str().unwrap();
+    let vertices = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+
+    // The plates are checked before anything is sent to CSPICE.
+    for plates in [[[0, 1, 2]], [[1, 2, 4]]] {
+        assert!(matches!(
+            spice::DskWriter::new(1, 499, "IAU_MARS").write(path, &vertices, &plates),
+            Err(spice::Error::InvalidMesh(_))
+        ));
+    }
+    assert!(!std::path::Path::new(path).exists());
+}
+
+#[test]
+#[serial]
+fn dsk_segments() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+    let handle = spice::dasopr("/Users/gregoireh/data/spice-kernels/hera/kernels/dsk/g_08438mm_lgt_obj_didb_0000n00000_v002.bds");
+
+    let segments = spice::DskSegments::new(handle).collect::<Vec<_>>();
+    assert!(!segments.is_empty());
+
+    let (dladsc, descriptor) = &segments[0];
+    assert_eq!(
+        *descriptor,
+        spice::dskgd(handle, spice::dlabfs(handle).0).into()
+    );
+    assert_eq!(spice::dskz02(handle, *dladsc).1, 3072);
+    assert_eq!(descriptor.data_type, 2);
+    assert_eq!(descriptor.frame, "DIMORPHOS_FIXED");
+    assert_eq!(
+        descriptor.coordinate_system,
+        Some(spice::CoordinateSystem::Latitudinal)
+    );
+    assert!(descriptor.data_class.is_some());
+    assert!(descriptor.bounds[2][1] > descriptor.bounds[2][0]);
+
+    let backward = spice::DskSegments::new(handle)
+        .rev()
+        .map(|(_, descriptor)| descriptor)
+        .collect::<Vec<_>>();
+    let forward = segments
+        .into_iter()
+        .rev()
+        .map(|(_, descriptor)| descriptor)
+        .collect::<Vec<_>>();
+    assert_eq!(backward, forward);
+
+    let mut both = spice::DskSegments::new(handle);
+    let mut count = 0;
+    while both.next().is_some() {
+        count += 1;
+        if both.next_back().is_some() {
+            count += 1;
+        }
+    }
+    assert_eq!(count, forward.len());
+
+    spice::dascls(handle);
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
+#[test]
+fn dsk02_keywords() {
+    use spice::Dsk02Keyword;
+
+    assert_eq!(Dsk02Keyword::VertexCount.code(), 1);
+    assert_eq!(Dsk02Keyword::VoxelPlateList.code(), 11);
+    assert_eq!(Dsk02Keyword::Vertices.code(), 19);
+    for (code, keyword) in Dsk02Keyword::ALL.iter().enumerate() {
+        assert_eq!(keyword.code(), code as i32 + 1);
+        assert_eq!(Dsk02Keyword::from_code(keyword.code()), Some(*keyword));
+    }
+    assert_eq!(Dsk02Keyword::from_code(0), None);
+    assert!(Dsk02Keyword::VoxelOrigin.is_double());
+    assert!(!Dsk02Keyword::Plates.is_double());
+}
+
+#[test]
+#[serial]
+fn dsk02_internals() {
+    use spice::Dsk02Keyword;
+
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+    let handle = spice::dasopr("/Users/gregoireh/data/spice-kernels/hera/kernels/dsk/g_08438mm_lgt_obj_didb_0000n00000_v002.bds");
+    let (dladsc, _) = spice::dlabfs(handle);
+
+    let (nv, np, nvxtot, vtxbds, voxsiz, voxori, vgrext, cgscal, vtxnpl, voxnpt, voxnpl) =
+        spice::dskb02(handle, dladsc);
+    assert_eq!((nv, np), spice::dskz02(handle, dladsc));
+    assert_eq!(np, 3072);
+    assert_eq!(nvxtot, vgrext.iter().product::<i32>());
+    assert!(voxsiz > 0.0);
+    assert!(cgscal > 0);
+
+    let int = |item| spice::dski02(handle, dladsc, item).unwrap();
+    let double = |item| spice::dskd02(handle, dladsc, item).unwrap();
+    assert_eq!(int(Dsk02Keyword::VertexCount), vec![nv]);
+    assert_eq!(int(Dsk02Keyword::VoxelCount), vec![nvxtot]);
+    assert_eq!(int(Dsk02Keyword::VoxelGridExtent), vgrext.to_vec());
+    assert_eq!(int(Dsk02Keyword::CoarseVoxelScale), vec![cgscal]);
+    assert_eq!(int(Dsk02Keyword::VertexPlateListSize), vec![vtxnpl]);
+    assert_eq!(int(Dsk02Keyword::VoxelPointerSize), vec![voxnpt]);
+    assert_eq!(int(Dsk02Keyword::VoxelPlateListSize), vec![voxnpl]);
+    assert_eq!(int(Dsk02Keyword::VoxelPointers).len(), voxnpt as usize);
+    assert_eq!(int(Dsk02Keyword::VoxelPlateList).len(), voxnpl as usize);
+    assert_eq!(
+        int(Dsk02Keyword::Plates)
+            .chunks(3)
+            .map(|plate| [plate[0], plate[1], plate[2]])
+            .collect::<Vec<_>>(),
+        spice::dskp02(handle, dladsc)
+    );
+    assert_eq!(double(Dsk02Keyword::VoxelOrigin), voxori.to_vec());
+    assert_eq!(double(Dsk02Keyword::VoxelSize), vec![voxsiz]);
+    assert_eq!(
+        double(Dsk02Keyword::VertexBounds),
+        vtxbds.iter().flatten().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(double(Dsk02Keyword::Vertices).len(), 3 * nv as usize);
+    assert!(matches!(
+        spice::dski02(handle, dladsc, Dsk02Keyword::Vertices),
+        Err(spice::Error::InvalidItem(_))
+    ));
+    assert!(matches!(
+        spice::dskd02(handle, dladsc, Dsk02Keyword::Plates),
+        Err(spice::Error::InvalidItem(_))
+    ));
+
+    spice::dascls(handle);
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
 #[test]
 #[serial]
 fn dskp02() {
@@ -49,6 +311,66 @@ fn dskp02() {
     spice::kclear();
 }
 
+#[test]
+#[serial]
+fn dsk_surface() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+    let method = "DSK/UNPRIORITIZED";
+    let lonlat = [[0.0, 0.0], [1.0, 0.5], [-2.0, -1.0], [3.0, 1.2]];
+
+    let points = spice::latsrf(method, "DIMORPHOS", et, "DIMORPHOS_FIXED", &lonlat);
+    let normals = spice::srfnrm(method, "DIMORPHOS", et, "DIMORPHOS_FIXED", &points);
+    assert_eq!(points.len(), lonlat.len());
+    for (point, normal) in multizip((points.iter(), normals.iter())) {
+        assert_relative_eq!(spice::vdot(*normal, *normal), 1.0, epsilon = 1e-12);
+        assert!(spice::vdot(*point, *normal) > 0.0);
+    }
+
+    // Rays from far away towards the center hit the surface at the points of latsrf.
+    let vertices = points
+        .iter()
+        .map(|p| [100.0 * p[0], 100.0 * p[1], 100.0 * p[2]])
+        .collect::<Vec<_>>();
+    let directions = points
+        .iter()
+        .map(|p| [-p[0], -p[1], -p[2]])
+        .collect::<Vec<_>>();
+    let (intercepts, found) = spice::dskxv(
+        false,
+        "DIMORPHOS",
+        &[],
+        et,
+        "DIMORPHOS_FIXED",
+        &vertices,
+        &directions,
+    );
+    assert!(found.iter().all(|&found| found));
+    for (intercept, point) in multizip((intercepts.iter(), points.iter())) {
+        for (component, expected) in multizip((intercept.iter(), point.iter())) {
+            assert_relative_eq!(component, expected, epsilon = 1e-9);
+        }
+    }
+
+    let (xpt, handle, _, dskdsc, _, ic, found) = spice::dskxsi(
+        false,
+        "DIMORPHOS",
+        &[],
+        et,
+        "DIMORPHOS_FIXED",
+        vertices[1],
+        directions[1],
+    );
+    assert!(found);
+    assert!(handle != 0);
+    assert_eq!(dskdsc.dtype, 2);
+    assert!(ic[0] > 0);
+    assert_eq!(xpt, intercepts[1]);
+
+    spice::kclear();
+}
+
 #[test]
 #[serial]
 fn georec() {
@@ -86,6 +408,147 @@ fn georec() {
     }
 }
 
+#[test]
+#[serial]
+fn coordinates() {
+    use spice::{AzEl, Cylindrical, Geodetic, Latitudinal, Planetographic, RaDec, Spherical};
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    let rectan = [1.0, 1.0, 0.0];
+
+    let latitudinal = Latitudinal::from(rectan);
+    assert_relative_eq!(latitudinal.radius, 2.0_f64.sqrt(), epsilon = f64::EPSILON);
+    assert_relative_eq!(latitudinal.longitude, FRAC_PI_4, epsilon = f64::EPSILON);
+    assert_relative_eq!(latitudinal.latitude, 0.0, epsilon = f64::EPSILON);
+
+    let spherical = Spherical::from(rectan);
+    assert_relative_eq!(spherical.colatitude, FRAC_PI_2, epsilon = f64::EPSILON);
+    assert_relative_eq!(spherical.longitude, FRAC_PI_4, epsilon = f64::EPSILON);
+
+    let cylindrical = Cylindrical::from([-1.0, 0.0, 2.0]);
+    assert_relative_eq!(cylindrical.radius, 1.0, epsilon = f64::EPSILON);
+    assert_relative_eq!(
+        cylindrical.longitude,
+        2.0 * FRAC_PI_2,
+        epsilon = f64::EPSILON
+    );
+    assert_relative_eq!(cylindrical.z, 2.0, epsilon = f64::EPSILON);
+
+    let radec = RaDec::from([0.0, -1.0, 0.0]);
+    assert_relative_eq!(radec.ra, 3.0 * FRAC_PI_2, epsilon = f64::EPSILON);
+    assert_relative_eq!(radec.dec, 0.0, epsilon = f64::EPSILON);
+
+    // Clockwise azimuth and elevation positive towards +Z, as in topocentric frames.
+    let azel = AzEl::from_rectangular([0.0, 1.0, 1.0], false, true);
+    assert_relative_eq!(azel.azimuth, 3.0 * FRAC_PI_2, epsilon = f64::EPSILON);
+    assert_relative_eq!(azel.elevation, FRAC_PI_4, epsilon = f64::EPSILON);
+
+    let vector = [1234.5, -2345.6, 3456.7];
+    let round_trips: [[f64; 3]; 6] = [
+        Latitudinal::from(vector).into(),
+        Spherical::from(vector).into(),
+        Cylindrical::from(vector).into(),
+        RaDec::from(vector).into(),
+        Geodetic::from_rectangular(vector, 6378.1366, 1.0 / 298.257)
+            .to_rectangular(6378.1366, 1.0 / 298.257),
+        AzEl::from_rectangular(vector, true, false).to_rectangular(true, false),
+    ];
+    for round_trip in round_trips.iter() {
+        for (component, expected) in multizip((round_trip.iter(), vector.iter())) {
+            assert_relative_eq!(component, expected, epsilon = 1e-9);
+        }
+    }
+
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    // The planetographic longitude of the Earth is positive east by convention, unlike other
+    // prograde bodies.
+    let earth = Planetographic::from_rectangular("EARTH", [0.0, 7000.0, 0.0], 6378.1366, 0.0);
+    assert_relative_eq!(earth.longitude, FRAC_PI_2, epsilon = f64::EPSILON);
+    assert_relative_eq!(earth.altitude, 7000.0 - 6378.1366, epsilon = 1e-9);
+
+    let back = earth.to_rectangular("EARTH", 6378.1366, 0.0);
+    assert_relative_eq!(back[1], 7000.0, epsilon = 1e-9);
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
+#[test]
+#[serial]
+fn jacobians() {
+    let [x, y, z] = [1234.5, -2345.6, 3456.7];
+    let (re, f) = (6378.1366, 1.0 / 298.257);
+
+    let product = |a: [[f64; 3]; 3], b: [[f64; 3]; 3]| {
+        let mut m = [[0.0; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                m[i][j] = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+            }
+        }
+        m
+    };
+
+    let (r, lon, lat) = spice::reclat([x, y, z]);
+    let (rs, colat, slon) = spice::recsph([x, y, z]);
+    let (rc, clon, cz) = spice::reccyl([x, y, z]);
+    let (glon, glat, alt) = spice::recgeo([x, y, z], re, f);
+
+    // Each Jacobian is the inverse of the one of the reverse transformation.
+    for identity in [
+        product(spice::dlatdr(x, y, z), spice::drdlat(r, lon, lat)),
+        product(spice::dsphdr(x, y, z), spice::drdsph(rs, colat, slon)),
+        product(spice::dcyldr(x, y, z), spice::drdcyl(rc, clon, cz)),
+        product(
+            spice::dgeodr(x, y, z, re, f),
+            spice::drdgeo(glon, glat, alt, re, f),
+        ),
+    ] {
+        for (i, row) in identity.iter().enumerate() {
+            for (j, component) in row.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(*component, expected, epsilon = 1e-12);
+            }
+        }
+    }
+
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let [plon, plat, palt] = spice::recpgr("EARTH", [x, y, z], re, f);
+    let identity = product(
+        spice::dpgrdr("EARTH", x, y, z, re, f),
+        spice::drdpgr("EARTH", plon, plat, palt, re, f),
+    );
+    for (i, row) in identity.iter().enumerate() {
+        for (j, component) in row.iter().enumerate() {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert_relative_eq!(*component, expected, epsilon = 1e-12);
+        }
+    }
+
+    // The velocity of a state follows the Jacobian.
+    let state = [x, y, z, 1.0, -2.0, 0.5];
+    let latitudinal = spice::xfmsta(state, "RECTANGULAR", "LATITUDINAL", " ");
+    let jacobi = spice::dlatdr(x, y, z);
+    assert_relative_eq!(latitudinal[0], r, epsilon = 1e-9);
+    for i in 0..3 {
+        let expected: f64 = (0..3).map(|k| jacobi[i][k] * state[3 + k]).sum();
+        assert_relative_eq!(latitudinal[3 + i], expected, epsilon = 1e-12);
+    }
+
+    let back = spice::xfmsta(
+        spice::xfmsta(state, "RECTANGULAR", "GEODETIC", "EARTH"),
+        "GEODETIC",
+        "RECTANGULAR",
+        "EARTH",
+    );
+    for (component, expected) in multizip((back.iter(), state.iter())) {
+        assert_relative_eq!(component, expected, epsilon = 1e-9);
+    }
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
 #[test]
 #[serial]
 fn pxform() {
@@ -109,6 +572,130 @@ fn pxform() {
     spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
 }
 
+#[test]
+#[serial]
+fn algebra() {
+    use spice::algebra;
+
+    let rows = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+    let matrix = algebra::matrix3(rows);
+
+    // Element at row 0 and column 1, whatever the storage.
+    assert_eq!(matrix[(0, 1)], 2.0);
+    assert_eq!(algebra::rows3(&matrix), rows);
+    assert_eq!(
+        matrix * algebra::vector3([1.0, 0.0, 0.0]),
+        na::Vector3::new(1.0, 4.0, 7.0)
+    );
+
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+
+    assert_eq!(
+        algebra::mxv(&matrix, &na::Vector3::new(1.0, 0.0, 0.0)),
+        na::Vector3::new(1.0, 4.0, 7.0)
+    );
+    assert_eq!(algebra::xpose(&matrix), matrix.transpose());
+
+    let rotation = algebra::pxform("J2000", "ECLIPJ2000", et);
+    let (position, light_time) = algebra::spkpos("DIMORPHOS", et, "J2000", "NONE", "SUN");
+    let (expected, expected_light_time) =
+        spice::spkpos("DIMORPHOS", et, "ECLIPJ2000", "NONE", "SUN");
+
+    assert_eq!(light_time, expected_light_time);
+    for (component, expected) in multizip(((rotation * position).iter(), expected.iter())) {
+        assert_relative_eq!(component, expected, epsilon = 1e-6);
+    }
+
+    let (state, _) = algebra::spkezr("DIMORPHOS", et, "J2000", "NONE", "SUN");
+    assert_eq!(state.fixed_rows::<3>(0), position);
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
+#[test]
+#[serial]
+fn rotations() {
+    use spice::algebra;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    // SPICE quaternion of the rotation of vectors by +90 degrees about +Z.
+    let q = [FRAC_PI_4.cos(), 0.0, 0.0, FRAC_PI_4.sin()];
+    let rotation = algebra::quaternion(q);
+
+    let x = na::Vector3::x();
+    assert_relative_eq!(rotation * x, na::Vector3::y(), epsilon = f64::EPSILON);
+    assert_relative_eq!(
+        rotation,
+        na::UnitQuaternion::from_axis_angle(&na::Vector3::z_axis(), FRAC_PI_2),
+        epsilon = f64::EPSILON
+    );
+    assert_eq!(algebra::spice_quaternion(&rotation), q);
+    assert_eq!(
+        algebra::spice_quaternion(&algebra::quaternion([-q[0], 0.0, 0.0, -q[3]])),
+        q
+    );
+
+    // The engineering quaternion of the same matrix has the opposite vector part, scalar last.
+    let engineering = algebra::spice_to_engineering(q);
+    assert_eq!(engineering, [-0.0, -0.0, -FRAC_PI_4.sin(), FRAC_PI_4.cos()]);
+    assert_eq!(algebra::engineering_to_spice(engineering), q);
+
+    let expected = [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+
+    let m = spice::q2m(q);
+    for (row, expected_row) in multizip((m.iter(), expected.iter())) {
+        for (component, expected_component) in multizip((row.iter(), expected_row.iter())) {
+            assert_relative_eq!(component, expected_component, epsilon = f64::EPSILON);
+        }
+    }
+    for (component, expected) in multizip((spice::m2q(m).iter(), q.iter())) {
+        assert_relative_eq!(component, expected, epsilon = f64::EPSILON);
+    }
+    assert_relative_eq!(
+        algebra::q2m(&rotation),
+        algebra::matrix3(expected),
+        epsilon = f64::EPSILON
+    );
+    assert_relative_eq!(
+        algebra::m2q(&algebra::matrix3(expected)),
+        rotation,
+        epsilon = f64::EPSILON
+    );
+
+    // `axisar` rotates vectors, as quaternions do.
+    assert_relative_eq!(
+        algebra::matrix3(spice::axisar([0.0, 0.0, 1.0], FRAC_PI_2)),
+        algebra::matrix3(expected),
+        epsilon = f64::EPSILON
+    );
+    let (axis, angle) = spice::raxisa(expected);
+    assert_relative_eq!(
+        algebra::vector3(axis),
+        na::Vector3::z(),
+        epsilon = f64::EPSILON
+    );
+    assert_relative_eq!(angle, FRAC_PI_2, epsilon = f64::EPSILON);
+    let (axis, angle) = algebra::raxisa(&algebra::axisar(&na::Vector3::z(), FRAC_PI_2).into());
+    assert_relative_eq!(axis.into_inner(), na::Vector3::z(), epsilon = f64::EPSILON);
+    assert_relative_eq!(angle, FRAC_PI_2, epsilon = f64::EPSILON);
+
+    // `eul2m` rotates frames: its matrix is the transpose of the one rotating vectors.
+    let m = spice::eul2m(FRAC_PI_2, 0.0, 0.0, 3, 1, 3);
+    assert_relative_eq!(
+        algebra::matrix3(m),
+        algebra::matrix3(expected).transpose(),
+        epsilon = f64::EPSILON
+    );
+
+    let m = spice::eul2m(0.3, -0.2, 0.1, 3, 2, 1);
+    let (angle3, angle2, angle1) = spice::m2eul(m, 3, 2, 1);
+    assert_relative_eq!(angle3, 0.3, epsilon = 1e-12);
+    assert_relative_eq!(angle2, -0.2, epsilon = 1e-12);
+    assert_relative_eq!(angle1, 0.1, epsilon = 1e-12);
+}
+
 #[test]
 #[serial]
 fn pxfrm2() {
@@ -328,96 +915,1534 @@ fn spkpos() {
 
 #[test]
 #[serial]
-fn str2et() {
+fn spkez() {
     spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
 
     let et = spice::str2et("2027-MAR-23 16:00:00");
+    let (dimorphos, _) = spice::bodn2c("DIMORPHOS");
+    let (hera, _) = spice::bodn2c("HERA");
+
+    let (state, light_time) = spice::spkez(dimorphos, et, "J2000", "LT+S", hera);
+    let (expected_state, expected_light_time) =
+        spice::spkezr("DIMORPHOS", et, "J2000", "LT+S", "HERA");
+    assert_eq!(state, expected_state);
+    assert_eq!(light_time, expected_light_time);
+
+    let (position, _) = spice::spkezp(dimorphos, et, "J2000", spice::Aberration::LtS, hera);
+    assert_eq!(position[..], expected_state[..3]);
+
+    let (geometric_state, _) = spice::spkgeo(dimorphos, et, "J2000", hera);
+    let (geometric_position, _) = spice::spkgps(dimorphos, et, "J2000", hera);
+    assert_eq!(geometric_position[..], geometric_state[..3]);
+
+    let ssb_dimorphos = spice::spkssb(dimorphos, et, "J2000");
+    let ssb_hera = spice::spkssb(hera, et, "J2000");
+    for i in 0..6 {
+        assert_relative_eq!(
+            ssb_dimorphos[i] - ssb_hera[i],
+            geometric_state[i],
+            epsilon = 1e-6
+        );
+    }
 
-    assert_relative_eq!(et, 859089669.1856234, epsilon = f64::EPSILON);
+    let (apparent_position, _) = spice::spkapo(dimorphos, et, "J2000", ssb_hera, "LT+S");
+    let (acs_state, _, _) = spice::spkacs(dimorphos, et, "J2000", "LT+S", hera);
+    for i in 0..3 {
+        assert_relative_eq!(apparent_position[i], acs_state[i], epsilon = 1e-6);
+    }
 
     spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
 }
 
 #[test]
 #[serial]
-fn timout() {
+fn str2et() {
     spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
 
     let et = spice::str2et("2027-MAR-23 16:00:00");
 
-    let date = spice::timout(et, spice::TIME_FORMAT);
-
-    assert_eq!(date, "2027-MAR-23 16:00:00");
+    assert_relative_eq!(et, 859089669.1856234, epsilon = f64::EPSILON);
 
     spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
 }
 
 #[test]
 #[serial]
-fn vdot() {
-    assert_eq!(spice::vdot([1.0, 2.0, 3.0], [1.0, 2.0, 3.0]), 14.0)
-}
+fn epoch() {
+    use spice::Epoch;
+    use std::time::Duration;
 
-#[test]
-#[serial]
-fn vcrss() {
-    // Examples from https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/vcrss_c.html
-    assert_eq!(
-        spice::vcrss([0.0, 1.0, 0.0], [1.0, 0.0, 0.0]),
-        [0.0, 0.0, -1.0]
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let epoch: Epoch = "2027-MAR-23 16:00:00".parse().unwrap();
+    assert_relative_eq!(epoch.et(), 859089669.1856234, epsilon = f64::EPSILON);
+    assert_eq!(epoch.to_string(), "2027-MAR-23 16:00:00");
+    assert_eq!(epoch.to_utc("ISOC", 0), "2027-03-23T16:00:00");
+    assert!("2027-MAR-32 16:00:00".parse::<Epoch>().is_err());
+
+    let later = epoch + Duration::from_secs(3600);
+    assert!(later > epoch);
+    assert_relative_eq!(later - epoch, 3600.0);
+    assert_eq!(later.to_string(), "2027-MAR-23 17:00:00");
+    assert_eq!(later - Duration::from_secs(3600), epoch);
+
+    // 2027-MAR-23 16:00:00 UTC is MJD 61487.666... in UTC.
+    assert_relative_eq!(epoch.mjd_utc(), 61487.0 + 2.0 / 3.0, epsilon = 1e-9);
+    assert_relative_eq!(
+        Epoch::from_mjd_utc(epoch.mjd_utc()).et(),
+        epoch.et(),
+        epsilon = 1e-5
     );
-    assert_eq!(
-        spice::vcrss([5.0, 5.0, 5.0], [-1.0, -1.0, -1.0]),
-        [0.0, 0.0, 0.0]
+    assert_relative_eq!(
+        Epoch::from_jd_tdb(epoch.jd_tdb()).et(),
+        epoch.et(),
+        epsilon = 1e-5
+    );
+    assert_relative_eq!(
+        Epoch::from_jd_tt(epoch.jd_tt()).et(),
+        epoch.et(),
+        epsilon = 1e-5
     );
+    assert_relative_eq!(
+        Epoch::from_tai_seconds(epoch.tai_seconds()).et(),
+        epoch.et(),
+        epsilon = 1e-6
+    );
+    assert_relative_eq!(
+        Epoch::from_gps_seconds(epoch.gps_seconds()).et(),
+        epoch.et(),
+        epsilon = 1e-6
+    );
+    assert_relative_eq!(
+        epoch.tai_seconds() - epoch.utc_seconds(),
+        37.0,
+        epsilon = 1e-9
+    );
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
 }
 
+#[cfg(feature = "chrono")]
 #[test]
 #[serial]
-fn vsep() {
-    let ang_1 = spice::vsep([1., 0., 0.], [1., 0., 0.]);
-    let ang_2 = spice::vsep([1., 0., 0.], [0., 1., 0.]);
+fn epoch_chrono() {
+    use chrono::{DateTime, NaiveDate, Utc};
+    use spice::Epoch;
 
-    assert_relative_eq!(ang_1, 0.0, epsilon = f64::EPSILON);
-    assert_relative_eq!(ang_2, std::f64::consts::TAU / 4., epsilon = f64::EPSILON);
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let datetime = NaiveDate::from_ymd_opt(2027, 3, 23)
+        .unwrap()
+        .and_hms_milli_opt(16, 0, 0, 250)
+        .unwrap()
+        .and_utc();
+    let epoch = Epoch::from(datetime);
+    assert_relative_eq!(
+        epoch.et(),
+        spice::str2et("2027-03-23T16:00:00.25"),
+        epsilon = 1e-6
+    );
+    assert_eq!(DateTime::<Utc>::from(epoch), datetime);
+
+    // The leap second ending 2016 is represented in chrono with more than a second of nanoseconds.
+    let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_milli_opt(23, 59, 59, 1_500)
+        .unwrap()
+        .and_utc();
+    let epoch = Epoch::from(leap);
+    assert_relative_eq!(
+        epoch.et(),
+        spice::str2et("2016-12-31T23:59:60.5"),
+        epsilon = 1e-6
+    );
+    assert_eq!(DateTime::<Utc>::from(epoch), leap);
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
 }
 
+#[cfg(feature = "hifitime")]
 #[test]
 #[serial]
-fn kdata() {
-    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
-    let index_dsk = 1;
+fn epoch_hifitime() {
+    use spice::Epoch;
 
-    let count = spice::ktotal("dsk");
-    assert_eq!(count, 2);
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
 
-    let (file, filtyp, source, handle, found) = spice::kdata(index_dsk, "dsk");
-    assert_eq!(
-        file,
-        "/Users/gregoireh/data/spice-kernels/hera/kernels/dsk/g_08438mm_lgt_obj_didb_0000n00000_v002.bds"
+    let instant = hifitime::Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 59, 0);
+    let epoch = Epoch::from(instant);
+    assert_relative_eq!(
+        epoch.et(),
+        spice::str2et("2016-12-31T23:59:59"),
+        epsilon = 1e-6
     );
-    assert_eq!(filtyp, "DSK");
-    assert_eq!(source, "/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
-    assert!(handle.is_positive());
-    assert_eq!(found, true);
 
-    spice::kclear();
+    let back = hifitime::Epoch::from(epoch);
+    assert!((back - instant).abs() < hifitime::Unit::Microsecond * 1);
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
 }
 
 #[test]
 #[serial]
-fn cell() {
+fn timout() {
     spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
 
-    let (file, _, _, _, found) = spice::kdata(1, "dsk");
-    assert!(found);
+    let et = spice::str2et("2027-MAR-23 16:00:00");
 
-    let cell = spice::dskobj(&file);
+    let date = spice::timout(et, spice::TIME_FORMAT);
 
-    assert_eq!(cell.card, 1);
-    assert_eq!(cell.get_data_int(0), -658031);
+    assert_eq!(date, "2027-MAR-23 16:00:00");
 
-    assert_eq!(spice::bodc2n(cell.get_data_int(0)).0, "DIMORPHOS");
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
+#[test]
+#[serial]
+fn time_format() {
+    use spice::{MonthStyle, Rounding, TimeFormat, TimeSystem, UtcFormat};
+
+    assert_eq!(TimeFormat::default().picture(), spice::TIME_FORMAT);
+    assert_eq!(
+        TimeFormat::day_of_year().fraction_digits(3).picture(),
+        "YYYY-DOY // HR:MN:SC.###"
+    );
+    assert_eq!(
+        TimeFormat::calendar()
+            .month(MonthStyle::FullTitle)
+            .two_digit_year()
+            .date_only()
+            .picture(),
+        "YR-Month-DD"
+    );
+    assert_eq!(
+        TimeFormat::julian_date()
+            .fraction_digits(5)
+            .time_system(TimeSystem::Tdb)
+            .rounding(Rounding::Round)
+            .to_string(),
+        "JD JULIAND.##### ::TDB ::RND"
+    );
+    assert_eq!(TimeFormat::iso_day_of_year().picture(), "YYYY-DOYTHR:MN:SC");
+
+    assert_eq!("isoc".parse::<UtcFormat>().unwrap(), UtcFormat::IsoCalendar);
+    assert_eq!(UtcFormat::from("J"), UtcFormat::JulianDate);
+    assert_eq!(
+        "X".parse::<UtcFormat>(),
+        Err(spice::Error::InvalidUtcFormat("X".to_string()))
+    );
+
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let epoch = spice::Epoch::from_utc("2027-MAR-23 16:00:00").unwrap();
+
+    assert_eq!(
+        spice::et2utc(epoch.et(), UtcFormat::IsoCalendar, 3),
+        "2027-03-23T16:00:00.000"
+    );
+    assert_eq!(spice::et2utc(epoch.et(), "ISOD", 0), "2027-082T16:00:00");
+    assert_eq!(
+        epoch.format(&TimeFormat::iso_calendar().fraction_digits(3)),
+        "2027-03-23T16:00:00.000"
+    );
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
+#[test]
+#[serial]
+fn tpictr() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let sample = "Tue Mar 23 16:00:00.250 2027";
+    let pictur = spice::tpictr(sample).unwrap();
+    let et = spice::str2et(sample);
+
+    assert_eq!(spice::raw::timout(et, &pictur, spice::MAX_LEN_OUT), sample);
+    assert!(matches!(
+        spice::tpictr("not a date"),
+        Err(spice::Error::InvalidTimeSample(_))
+    ));
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
+const LSK: &str = r"
+Leapseconds kernel, content of naif0012.tls.
+
+\begindata
+
+DELTET/DELTA_T_A       =   32.184
+DELTET/K               =    1.657D-3
+DELTET/EB              =    1.671D-2
+DELTET/M               = (  6.239996D0   1.99096871D-7 )
+
+DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
+                           11,   @1972-JUL-1
+                           12,   @1973-JAN-1
+                           13,   @1974-JAN-1
+                           14,   @1975-JAN-1
+                           15,   @1976-JAN-1
+                           16,   @1977-JAN-1
+                           17,   @1978-JAN-1
+                           18,   @1979-JAN-1
+                           19,   @1980-JAN-1
+                           20,   @1981-JUL-1
+                           21,   @1982-JUL-1
+                           22,   @1983-JUL-1
+                           23,   @1985-JUL-1
+                           24,   @1988-JAN-1
+                           25,   @1990-JAN-1
+                           26,   @1991-JAN-1
+                           27,   @1992-JUL-1
+                           28,   @1993-JUL-1
+                           29,   @1994-JUL-1
+                           30,   @1996-JAN-1
+                           31,   @1997-JUL-1
+                           32,   @1999-JAN-1
+                           33,   @2006-JAN-1
+                           34,   @2009-JAN-1
+                           35,   @2012-JUL-1
+                           36,   @2015-JUL-1
+                           37,   @2017-JAN-1 )
+
+\begintext
+";
+
+#[test]
+#[serial]
+fn leapseconds() {
+    let lsk: spice::LeapSeconds = LSK.parse().unwrap();
+
+    assert_eq!(lsk.delta_at().len(), 28);
+    assert_eq!(lsk.delta_at()[0], (10.0, -883_656_000.0));
+    assert_eq!(lsk.delta_at()[27], (37.0, 536_500_800.0));
+
+    let et = lsk.str2et("2000-01-01T12:00:00").unwrap();
+    assert_relative_eq!(et, 64.18392728473108, epsilon = f64::EPSILON * 64.0);
+    assert_relative_eq!(lsk.et_to_utc(et), 0.0, epsilon = 1e-9);
+
+    let et = lsk.str2et("2027-MAR-23 16:00:00").unwrap();
+    assert_eq!(lsk.str2et("2027-082T16:00:00").unwrap(), et);
+    assert_eq!(lsk.str2et("2027 March 23 16:00").unwrap(), et);
+    assert_eq!(lsk.str2et("2027-03-23T16").unwrap(), et);
+    assert_relative_eq!(lsk.utc_to_et(lsk.et_to_utc(et)), et, epsilon = 1e-9);
+
+    let leap = lsk.str2et("2016-12-31T23:59:60.5").unwrap();
+    let next = lsk.str2et("2017-01-01T00:00:00").unwrap();
+    assert_relative_eq!(next - leap, 0.5, epsilon = 1e-6);
+
+    assert!(matches!(
+        lsk.str2et("2027-02-29"),
+        Err(spice::Error::InvalidTime(_))
+    ));
+    assert!(matches!(
+        "DELTET/K = 1.657D-3".parse::<spice::LeapSeconds>(),
+        Err(spice::Error::InvalidKernel(_))
+    ));
+}
+
+#[test]
+#[serial]
+fn leapseconds_cspice() {
+    let path = "/Users/gregoireh/data/spice-kernels/hera/kernels/lsk/naif0012.tls";
+    let lsk = spice::LeapSeconds::from_file(path).unwrap();
+    spice::furnsh(path);
+
+    for date in [
+        "1950-01-01T00:00:00",
+        "1972-JAN-01 00:00:00",
+        "2000-01-01T12:00:00",
+        "2016-12-31T23:59:59.999",
+        "2016-12-31T23:59:60.5",
+        "2027-MAR-23 16:00:00.250",
+        "2027-082T16:00:00",
+    ] {
+        assert_eq!(lsk.str2et(date).unwrap(), spice::str2et(date));
+    }
+
+    for epoch in [-1e9, 0.0, 536_500_868.684, 8.5e8] {
+        assert_eq!(lsk.delta_from_utc(epoch), spice::deltet(epoch, "UTC"));
+        assert_eq!(lsk.delta_from_et(epoch), spice::deltet(epoch, "ET"));
+    }
+
+    spice::unload(path);
+}
+
+#[test]
+fn math() {
+    use spice::math;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    // The formula of vsep keeps the accuracy where the arc cosine of the dot product is 0.
+    let tiny = 1e-10;
+    assert_relative_eq!(
+        math::vsep(&[1.0, 0.0, 0.0], &[1.0, tiny, 0.0]),
+        tiny,
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        math::vsep(&[1.0, 0.0, 0.0], &[-1.0, tiny, 0.0]),
+        PI - tiny,
+        max_relative = f64::EPSILON
+    );
+    assert_eq!(math::vsep(&[0.0; 3], &[1.0, 0.0, 0.0]), 0.0);
+
+    // The scalings keep the magnitude of huge vectors finite.
+    assert_relative_eq!(
+        math::vnorm(&[1e300, 1e300, 0.0]),
+        2f64.sqrt() * 1e300,
+        max_relative = f64::EPSILON
+    );
+    assert_eq!(math::vhat(&[0.0; 3]), [0.0; 3]);
+    assert_eq!(math::ucrss(&[2.0, 0.0, 0.0], &[4.0, 0.0, 0.0]), [0.0; 3]);
+    assert_eq!(
+        math::vperp(&[3.0, 4.0, 0.0], &[2.0, 0.0, 0.0]),
+        [0.0, 4.0, 0.0]
+    );
+    assert_eq!(math::vproj(&[3.0, 4.0, 0.0], &[0.0; 3]), [0.0; 3]);
+
+    // The frame rotation of +90 degrees about Z gives the coordinates of X along -Y.
+    let rotation = math::rotate(FRAC_PI_2, 3);
+    for (component, expected) in multizip((
+        math::mxv(&rotation, &[1.0, 0.0, 0.0]).iter(),
+        [0.0, -1.0, 0.0].iter(),
+    )) {
+        assert_relative_eq!(component, expected, epsilon = f64::EPSILON);
+    }
+    assert_eq!(math::rotate(0.3, 0), math::rotate(0.3, 3));
+    assert_eq!(math::rotmat(&math::ident(), 0.3, 2), math::rotate(0.3, 2));
+    for (component, expected) in multizip((
+        math::vrotv(&[1.0, 0.0, 0.0], &[0.0, 0.0, 2.0], FRAC_PI_2).iter(),
+        [0.0, 1.0, 0.0].iter(),
+    )) {
+        assert_relative_eq!(component, expected, epsilon = f64::EPSILON);
+    }
+
+    let m = math::twovec(&[0.0, 0.0, 5.0], 3, &[1.0, 1.0, 0.0], 1).unwrap();
+    assert_eq!(m[2], [0.0, 0.0, 1.0]);
+    assert_relative_eq!(m[0][0], 0.5f64.sqrt(), epsilon = f64::EPSILON);
+    assert_relative_eq!(math::det(&m), 1.0, epsilon = f64::EPSILON);
+    assert!(math::twovec(&[1.0, 0.0, 0.0], 1, &[2.0, 0.0, 0.0], 2).is_none());
+    assert!(math::twovec(&[1.0, 0.0, 0.0], 1, &[0.0, 1.0, 0.0], 1).is_none());
+    assert!(math::twovec(&[1.0, 0.0, 0.0], 4, &[0.0, 1.0, 0.0], 1).is_none());
+}
+
+#[test]
+#[serial]
+fn math_cspice() {
+    use spice::{c, math};
+
+    let vectors = [
+        [1.0, 2.0, 3.0],
+        [-4.5, 0.25, 1e-3],
+        [1e-200, -3e-200, 2e-200],
+        [7e150, 1e150, -2e150],
+        [1.0, 1e-12, 0.0],
+        [-1.0, 0.0, 1e-9],
+        [0.0, 0.0, 0.0],
+    ];
+    let m1 = [[0.5, -1.0, 2.0], [3.0, 0.25, -0.75], [1.5, 2.5, -4.0]];
+    let m2 = [[-2.0, 1.0, 0.5], [0.0, 3.5, -1.25], [4.0, -0.5, 1.0]];
+
+    let assert_vector = |v: [f64; 3], expected: [f64; 3]| {
+        for (component, expected) in multizip((v.iter(), expected.iter())) {
+            assert_relative_eq!(component, expected, max_relative = 4.0 * f64::EPSILON);
+        }
+    };
+    let assert_matrix = |m: [[f64; 3]; 3], expected: [[f64; 3]; 3]| {
+        for (row, expected) in multizip((m.iter(), expected.iter())) {
+            assert_vector(*row, *expected);
+        }
+    };
+
+    unsafe {
+        for mut v1 in vectors {
+            let mut out = [0.0; 3];
+            c::vhat_c(v1.as_mut_ptr(), out.as_mut_ptr());
+            assert_vector(math::vhat(&v1), out);
+            assert_relative_eq!(
+                math::vnorm(&v1),
+                c::vnorm_c(v1.as_mut_ptr()),
+                max_relative = 4.0 * f64::EPSILON
+            );
+            c::rotvec_c(v1.as_mut_ptr(), 0.7, 2, out.as_mut_ptr());
+            assert_vector(math::rotvec(&v1, 0.7, 2), out);
+            c::mtxv_c(m1.as_ptr() as *mut _, v1.as_mut_ptr(), out.as_mut_ptr());
+            assert_vector(math::mtxv(&m1, &v1), out);
+
+            for mut v2 in vectors {
+                c::vproj_c(v1.as_mut_ptr(), v2.as_mut_ptr(), out.as_mut_ptr());
+                assert_vector(math::vproj(&v1, &v2), out);
+                c::vperp_c(v1.as_mut_ptr(), v2.as_mut_ptr(), out.as_mut_ptr());
+                assert_vector(math::vperp(&v1, &v2), out);
+                c::ucrss_c(v1.as_mut_ptr(), v2.as_mut_ptr(), out.as_mut_ptr());
+                assert_vector(math::ucrss(&v1, &v2), out);
+                c::vrotv_c(v1.as_mut_ptr(), v2.as_mut_ptr(), 1.2, out.as_mut_ptr());
+                assert_vector(math::vrotv(&v1, &v2, 1.2), out);
+                c::vlcom_c(
+                    2.0,
+                    v1.as_mut_ptr(),
+                    -0.5,
+                    v2.as_mut_ptr(),
+                    out.as_mut_ptr(),
+                );
+                assert_vector(math::vlcom(2.0, &v1, -0.5, &v2), out);
+                assert_relative_eq!(
+                    math::vsep(&v1, &v2),
+                    c::vsep_c(v1.as_mut_ptr(), v2.as_mut_ptr()),
+                    max_relative = 4.0 * f64::EPSILON
+                );
+                assert_relative_eq!(
+                    math::vtmv(&v1, &m1, &v2),
+                    c::vtmv_c(v1.as_mut_ptr(), m1.as_ptr() as *mut _, v2.as_mut_ptr()),
+                    max_relative = 4.0 * f64::EPSILON
+                );
+            }
+        }
+
+        let mut out = [[0.0; 3]; 3];
+        for iaxis in -1..=4 {
+            c::rotate_c(0.4, iaxis, out.as_mut_ptr());
+            assert_matrix(math::rotate(0.4, iaxis), out);
+            c::rotmat_c(m1.as_ptr() as *mut _, 0.4, iaxis, out.as_mut_ptr());
+            assert_matrix(math::rotmat(&m1, 0.4, iaxis), out);
+        }
+        for (indexa, indexp) in [(1, 2), (1, 3), (2, 1), (2, 3), (3, 1), (3, 2)] {
+            c::twovec_c(
+                vectors[0].as_ptr() as *mut _,
+                indexa,
+                vectors[1].as_ptr() as *mut _,
+                indexp,
+                out.as_mut_ptr(),
+            );
+            assert_matrix(
+                math::twovec(&vectors[0], indexa, &vectors[1], indexp).unwrap(),
+                out,
+            );
+        }
+        c::mxm_c(
+            m1.as_ptr() as *mut _,
+            m2.as_ptr() as *mut _,
+            out.as_mut_ptr(),
+        );
+        assert_matrix(math::mxm(&m1, &m2), out);
+        c::mtxm_c(
+            m1.as_ptr() as *mut _,
+            m2.as_ptr() as *mut _,
+            out.as_mut_ptr(),
+        );
+        assert_matrix(math::mtxm(&m1, &m2), out);
+        c::mxmt_c(
+            m1.as_ptr() as *mut _,
+            m2.as_ptr() as *mut _,
+            out.as_mut_ptr(),
+        );
+        assert_matrix(math::mxmt(&m1, &m2), out);
+        assert_relative_eq!(
+            math::det(&m1),
+            c::det_c(m1.as_ptr() as *mut _),
+            max_relative = 4.0 * f64::EPSILON
+        );
+    }
+}
+
+#[test]
+fn bvh() {
+    use spice::{Bvh, PlateModel};
+
+    // Sphere of radius 2 made of latitude bands, whose poles are single vertices.
+    let (nlat, nlon) = (12, 24);
+    let mut vertices = vec![[0.0, 0.0, 2.0]];
+    for i in 1..nlat {
+        let colat = std::f64::consts::PI * i as f64 / nlat as f64;
+        for j in 0..nlon {
+            let lon = 2.0 * std::f64::consts::PI * j as f64 / nlon as f64;
+            vertices.push([
+                2.0 * colat.sin() * lon.cos(),
+                2.0 * colat.sin() * lon.sin(),
+                2.0 * colat.cos(),
+            ]);
+        }
+    }
+    vertices.push([0.0, 0.0, -2.0]);
+    let index = |i: i32, j: i32| 2 + (i - 1) * nlon + j.rem_euclid(nlon);
+    let south = vertices.len() as i32;
+    let mut plates = vec![];
+    for j in 0..nlon {
+        plates.push([1, index(1, j), index(1, j + 1)]);
+        plates.push([south, index(nlat - 1, j + 1), index(nlat - 1, j)]);
+        for i in 1..nlat - 1 {
+            plates.push([index(i, j), index(i + 1, j), index(i + 1, j + 1)]);
+            plates.push([index(i, j), index(i + 1, j + 1), index(i, j + 1)]);
+        }
+    }
+    let model = PlateModel::new(vertices, plates);
+    let bvh = Bvh::new(&model).unwrap();
+    assert_eq!(bvh.len(), model.plates.len());
+    assert!(Bvh::new(&PlateModel::default()).unwrap().is_empty());
+    for plate in [[0, 1, 2], [1, 2, model.vertices.len() as i32 + 1]] {
+        assert!(matches!(
+            Bvh::new(&PlateModel::new(model.vertices.clone(), vec![plate])),
+            Err(spice::Error::InvalidMesh(_))
+        ));
+    }
+
+    // Rays from random points around the sphere, aimed near its center or away from it.
+    let mut seed = 12345_u64;
+    let mut random = move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 11) as f64 / (1_u64 << 53) as f64 * 2.0 - 1.0
+    };
+    let mut vertices = vec![];
+    let mut raydirs = vec![];
+    for _ in 0..500 {
+        let vertex = [10.0 * random(), 10.0 * random(), 10.0 * random()];
+        let target = [random(), random(), random()];
+        vertices.push(vertex);
+        raydirs.push([
+            target[0] - vertex[0],
+            target[1] - vertex[1],
+            target[2] - vertex[2],
+        ]);
+    }
+    let hits = bvh.intersect_batch(&vertices, &raydirs);
+
+    // Every plate alone in a hierarchy gives the nearest hit by brute force.
+    let singles = (0..model.plates.len())
+        .map(|i| {
+            Bvh::new(&PlateModel::new(
+                model.vertices.clone(),
+                vec![model.plates[i]],
+            ))
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let mut count = 0;
+    for (k, hit) in hits.iter().enumerate() {
+        assert_eq!(*hit, bvh.intersect(&vertices[k], &raydirs[k]));
+        let nearest = singles
+            .iter()
+            .enumerate()
+            .filter_map(|(i, single)| single.intersect(&vertices[k], &raydirs[k]).map(|h| (i, h)))
+            .min_by(|(_, a), (_, b)| a.distance.total_cmp(&b.distance));
+        match (hit, nearest) {
+            (Some(hit), Some((_, nearest))) => {
+                count += 1;
+                assert_relative_eq!(hit.distance, nearest.distance, epsilon = 1e-12);
+                let norm = spice::math::vnorm(&hit.point);
+                assert!(norm <= 2.0 + 1e-12 && norm > 1.9);
+                let expected = spice::math::vdist(&vertices[k], &hit.point);
+                assert_relative_eq!(hit.distance, expected, epsilon = 1e-12);
+            }
+            (None, None) => {}
+            _ => panic!("hit {:?} differs from brute force {:?}", hit, nearest),
+        }
+    }
+    assert!(count > 100);
+
+    // Rays leaving the sphere from its center hit it, rays pointing away from it do not.
+    let hit = bvh.intersect(&[0.0; 3], &[0.0, 0.0, 1.0]).unwrap();
+    assert_relative_eq!(hit.distance, 2.0, epsilon = 1e-12);
+    assert!(bvh.intersect(&[0.0, 0.0, 3.0], &[0.0, 0.0, 1.0]).is_none());
+    assert!(bvh.intersect(&[0.0, 0.0, 3.0], &[0.0; 3]).is_none());
+}
+
+#[test]
+#[serial]
+fn bvh_dsk() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+    let handle = spice::dasopr("/Users/gregoireh/data/spice-kernels/hera/kernels/dsk/g_08438mm_lgt_obj_didb_0000n00000_v002.bds");
+    let (dladsc, _) = spice::dlabfs(handle);
+
+    let model = spice::PlateModel::from_dsk(handle, dladsc);
+    let bvh = spice::Bvh::new(&model).unwrap();
+    let vertices = (0..100)
+        .map(|i| {
+            let angle = i as f64 * 0.37;
+            [angle.cos(), angle.sin(), 0.1 * (i % 7) as f64 - 0.3]
+        })
+        .collect::<Vec<_>>();
+    let raydirs = vertices
+        .iter()
+        .map(|vertex| [-vertex[0], -vertex[1], -vertex[2]])
+        .collect::<Vec<_>>();
+
+    for (hit, (vertex, raydir)) in bvh
+        .intersect_batch(&vertices, &raydirs)
+        .into_iter()
+        .zip(vertices.iter().zip(&raydirs))
+    {
+        let (plid, xpt, found) = spice::dskx02(handle, dladsc, *vertex, *raydir);
+        let hit = hit.unwrap();
+        assert!(found);
+        assert_eq!(hit.plate, plid);
+        assert_relative_eq!(hit.point[0], xpt[0], epsilon = 1e-9);
+        assert_relative_eq!(hit.point[1], xpt[1], epsilon = 1e-9);
+        assert_relative_eq!(hit.point[2], xpt[2], epsilon = 1e-9);
+    }
+
+    spice::dascls(handle);
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
+#[test]
+fn pixel_map() {
+    use spice::{PixelMap, Reflectance};
+
+    assert_eq!(Reflectance::Lambert.brightness(0.0, 1.0), 1.0);
+    assert_relative_eq!(
+        Reflectance::Lambert.brightness(std::f64::consts::FRAC_PI_3, 0.0),
+        0.5,
+        epsilon = 1e-15
+    );
+    assert_eq!(Reflectance::LommelSeeliger.brightness(0.0, 0.0), 0.5);
+    assert_eq!(Reflectance::Lambert.brightness(2.0, 0.0), 0.0);
+
+    let mut map = PixelMap::new(3, 2);
+    assert!(map.values.iter().all(|v| v.is_nan()));
+    map.values[..5].copy_from_slice(&[0.0, 0.5, 1.0, 2.0, 4.0]);
+    assert_eq!(map.get(1, 1), 4.0);
+    assert_eq!(map.gray(), vec![0, 32, 64, 128, 255, 0]);
+
+    let mut raw = vec![];
+    map.write_raw(&mut raw).unwrap();
+    assert_eq!(raw.len(), 6 * 8);
+    assert_eq!(raw[24..32], 2.0_f64.to_le_bytes());
+
+    let mut pgm = vec![];
+    map.write_pgm(&mut pgm).unwrap();
+    assert_eq!(pgm[..11], *b"P5\n3 2\n255\n");
+    assert_eq!(pgm[11..], map.gray()[..]);
+
+    let mut png = vec![];
+    map.write_png(&mut png).unwrap();
+    assert_eq!(png[..8], *b"\x89PNG\r\n\x1a\n");
+    assert_eq!(png[12..16], *b"IHDR");
+    assert_eq!(png[16..24], [0, 0, 0, 3, 0, 0, 0, 2]);
+    // CRC of the IHDR chunk of an 8-bit grayscale image of 3 by 2 pixels.
+    assert_eq!(png[29..33], [0xb8, 0x1f, 0x39, 0xc6]);
+    // Stored deflate block holding the two filtered rows.
+    let idat = &png[33..];
+    assert_eq!(idat[4..8], *b"IDAT");
+    assert_eq!(idat[10..15], [1, 8, 0, !8, 0xff]);
+    assert_eq!(idat[15..23], [0, 0, 32, 64, 0, 128, 255, 0]);
+    // The IEND chunk always ends with the same CRC.
+    assert_eq!(
+        png[png.len() - 12..],
+        [0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]
+    );
+}
+
+#[test]
+fn renderer_directions() {
+    use spice::math::{vdot, vhat, vsep};
+
+    let renderer =
+        spice::Renderer::new("CAMERA", "TARGET", "TARGET_FIXED", "OBSERVER").resolution(5, 3);
+    let bsight = [0.0, 0.0, 2.0];
+    let tan = |v: &[f64; 3]| vsep(v, &[0.0, 0.0, 1.0]).tan();
+
+    // The image spans the square around the circle, whose corners are further than its radius.
+    let directions = renderer.directions("CIRCLE", &bsight, &[[0.1, 0.0, 1.0]]);
+    assert_eq!(directions.len(), 15);
+    assert_relative_eq!(tan(&directions[7]), 0.0, epsilon = 1e-12);
+    assert_relative_eq!(directions[5][0], -directions[9][0], epsilon = 1e-12);
+    assert_relative_eq!(directions[2][1], -directions[12][1], epsilon = 1e-12);
+    assert_relative_eq!(
+        directions[2][1].abs() / directions[2][2],
+        0.1 * 2.0 / 3.0,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        directions[9][0].abs() / directions[9][2],
+        0.1 * 4.0 / 5.0,
+        epsilon = 1e-12
+    );
+
+    // The image of an ellipse spans its axes, given by its two boundary vectors.
+    let directions = renderer.directions(
+        "ELLIPSE",
+        &bsight,
+        &[vhat(&[0.2, 0.0, 1.0]), vhat(&[0.0, 0.05, 1.0])],
+    );
+    assert_relative_eq!(
+        directions[9][0].abs() / directions[9][2],
+        0.2 * 4.0 / 5.0,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        directions[2][1].abs() / directions[2][2],
+        0.05 * 2.0 / 3.0,
+        epsilon = 1e-12
+    );
+    for direction in &directions {
+        assert_relative_eq!(vdot(direction, direction), 1.0, epsilon = 1e-12);
+    }
+}
+
+#[test]
+#[serial]
+fn renderer() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+
+    let rendering = spice::Renderer::new("HERA_AFC-1", "DIMORPHOS", "DIMORPHOS_FIXED", "HERA")
+        .method(spice::ShapeModel::dsk(&[]))
+        .resolution(32, 24)
+        .reflectance(spice::Reflectance::LommelSeeliger)
+        .render(et)
+        .unwrap();
+    assert_eq!(rendering.directions.len(), 32 * 24);
+    assert_eq!((rendering.range.width, rendering.range.height), (32, 24));
+
+    for pixel in 0..rendering.directions.len() {
+        assert_relative_eq!(
+            spice::math::vnorm(&rendering.directions[pixel]),
+            1.0,
+            epsilon = 1e-12
+        );
+        match rendering.intercepts[pixel] {
+            Some(spoint) => {
+                assert!(rendering.plates[pixel].is_some());
+                assert!(rendering.range.values[pixel] > 0.0);
+                assert!(
+                    spice::math::vdist(&spoint, &rendering.position)
+                        <= rendering.range.values[pixel] * 1.01
+                );
+                assert!((0.0..=1.0).contains(&rendering.brightness.values[pixel]));
+            }
+            None => assert!(rendering.range.values[pixel].is_nan()),
+        }
+    }
+
+    assert!(matches!(
+        spice::Renderer::new("NOT AN INSTRUMENT", "DIMORPHOS", "DIMORPHOS_FIXED", "HERA")
+            .render(et),
+        Err(spice::Error::UnknownName(_))
+    ));
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
+#[test]
+fn mass_properties() {
+    use spice::PlateModel;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    // Box of half-sizes 3, 2 and 1 centered on (1, -2, 0.5), whose plates face outwards.
+    let (half, center) = ([3.0, 2.0, 1.0], [1.0, -2.0, 0.5]);
+    let mut vertices = vec![];
+    for i in 0..8 {
+        let sign = |bit: usize| if i >> bit & 1 == 1 { 1.0 } else { -1.0 };
+        vertices.push([0, 1, 2].map(|k| center[k] + sign(k) * half[k]));
+    }
+    let quads = [
+        [1, 3, 4, 2],
+        [5, 6, 8, 7],
+        [1, 2, 6, 5],
+        [3, 7, 8, 4],
+        [1, 5, 7, 3],
+        [2, 4, 8, 6],
+    ];
+    let plates = quads
+        .iter()
+        .flat_map(|[a, b, c, d]| [[*a, *b, *c], [*a, *c, *d]])
+        .collect();
+    let model = PlateModel::new(vertices, plates);
+
+    let volume = 8.0 * half[0] * half[1] * half[2];
+    assert_relative_eq!(model.volume(), volume, epsilon = 1e-12);
+    assert_relative_eq!(model.area(), 8.0 * (6.0 + 3.0 + 2.0), epsilon = 1e-12);
+    assert_eq!(model.areas().len(), 12);
+    assert_relative_eq!(model.areas()[0], 12.0, epsilon = 1e-12);
+    assert_relative_eq!(
+        model.equivalent_radius(),
+        (3.0 * volume / (4.0 * PI)).cbrt(),
+        epsilon = 1e-12
+    );
+    let cof = model.center_of_figure();
+    for k in 0..3 {
+        assert_relative_eq!(cof[k], center[k], epsilon = 1e-12);
+    }
+
+    let inertia = model.inertia();
+    let [a2, b2, c2] = half.map(|h| h * h);
+    let expected = [b2 + c2, a2 + c2, a2 + b2].map(|s| volume * s / 3.0);
+    for (i, row) in inertia.iter().enumerate() {
+        for (j, element) in row.iter().enumerate() {
+            let expected = if i == j { expected[i] } else { 0.0 };
+            assert_relative_eq!(*element, expected, epsilon = 1e-10);
+        }
+    }
+
+    // The ellipsoid keeps the proportions of the box, with its volume.
+    let ellipsoid = model.equivalent_ellipsoid().unwrap();
+    let scale = (6.0 / PI).cbrt();
+    for k in 0..3 {
+        assert_relative_eq!(ellipsoid.radii[k], scale * half[k], epsilon = 1e-10);
+        assert_relative_eq!(ellipsoid.axes[k][k].abs(), 1.0, epsilon = 1e-10);
+        assert_relative_eq!(ellipsoid.center[k], center[k], epsilon = 1e-12);
+    }
+    assert_relative_eq!(spice::math::det(&ellipsoid.axes), 1.0, epsilon = 1e-10);
+
+    // Too few vertices to fit an ellipsoid, and no volume once flattened.
+    assert_eq!(model.best_fit_ellipsoid(), None);
+    let mut flat = model.clone();
+    flat.vertices.iter_mut().for_each(|v| v[2] = 0.0);
+    assert_eq!(flat.equivalent_ellipsoid(), None);
+
+    // Points of a rotated and shifted ellipsoid are fitted exactly.
+    let (radii, shift, angle) = ([3.0, 2.0, 1.0], [1.0, -2.0, 0.5], 0.3_f64);
+    let mut vertices = vec![];
+    for i in 1..8 {
+        let lat = PI * (i as f64 / 8.0 - 0.5);
+        for j in 0..12 {
+            let lon = 2.0 * PI * j as f64 / 12.0;
+            let [x, y, z] = [
+                radii[0] * lat.cos() * lon.cos(),
+                radii[1] * lat.cos() * lon.sin(),
+                radii[2] * lat.sin(),
+            ];
+            vertices.push([
+                shift[0] + angle.cos() * x - angle.sin() * y,
+                shift[1] + angle.sin() * x + angle.cos() * y,
+                shift[2] + z,
+            ]);
+        }
+    }
+    let fitted = PlateModel::new(vertices, vec![])
+        .best_fit_ellipsoid()
+        .unwrap();
+    for k in 0..3 {
+        assert_relative_eq!(fitted.radii[k], radii[k], epsilon = 1e-8);
+        assert_relative_eq!(fitted.center[k], shift[k], epsilon = 1e-8);
+    }
+    assert_relative_eq!(fitted.axes[0][0].abs(), angle.cos(), epsilon = 1e-8);
+    assert_relative_eq!(fitted.axes[2][2].abs(), 1.0, epsilon = 1e-8);
+    assert_relative_eq!(spice::math::det(&fitted.axes), 1.0, epsilon = 1e-10);
+
+    // Under a uniform gravity along -Z, the bottom is upside down and the sides are vertical.
+    let slopes = model.slopes(|_| [0.0, 0.0, -9.81]);
+    assert_eq!(slopes.len(), 12);
+    assert_relative_eq!(slopes[0], PI, epsilon = 1e-12);
+    assert_relative_eq!(slopes[2], 0.0, epsilon = 1e-12);
+    for slope in &slopes[4..] {
+        assert_relative_eq!(*slope, FRAC_PI_2, epsilon = 1e-12);
+    }
+    let centers = model.centers();
+    assert_relative_eq!(centers[2][2], center[2] + half[2], epsilon = 1e-12);
+}
+
+#[test]
+#[serial]
+fn mass_properties_dsk() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+    let handle = spice::dasopr("/Users/gregoireh/data/spice-kernels/hera/kernels/dsk/g_08438mm_lgt_obj_didb_0000n00000_v002.bds");
+    let (dladsc, _) = spice::dlabfs(handle);
+    let model = spice::PlateModel::from_dsk(handle, dladsc);
+    spice::dascls(handle);
+
+    let radius = model.equivalent_radius();
+    assert!(model.volume() > 0.0);
+    assert!(model.area() > 4.0 * std::f64::consts::PI * radius * radius);
+    assert!(spice::math::vnorm(&model.center_of_figure()) < radius);
+
+    let ellipsoid = model.equivalent_ellipsoid().unwrap();
+    assert!(ellipsoid.radii[0] >= ellipsoid.radii[1]);
+    assert!(ellipsoid.radii[1] >= ellipsoid.radii[2]);
+    assert!(ellipsoid.radii[2] > 0.0);
+    let fitted = model.best_fit_ellipsoid().unwrap();
+    assert!(fitted.radii[0] >= fitted.radii[2]);
+    assert!(spice::math::vnorm(&fitted.center) < radius);
+    assert!(model
+        .slopes(spice::math::vminus)
+        .iter()
+        .all(|slope| (0.0..=std::f64::consts::PI).contains(slope)));
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
+#[test]
+fn methods() {
+    use spice::{Error, ShapeModel, SubPointMethod};
+
+    let dsk = ShapeModel::Dsk {
+        surfaces: vec![1, -2],
+    };
+    assert_eq!(ShapeModel::Ellipsoid.to_string(), "ELLIPSOID");
+    assert_eq!(dsk.to_string(), "DSK/UNPRIORITIZED/SURFACES = 1, -2");
+    for shape in [ShapeModel::Ellipsoid, ShapeModel::dsk(&[]), dsk.clone()] {
+        assert_eq!(shape.to_string().parse(), Ok(shape));
+    }
+    assert_eq!(" ellipsoid ".parse(), Ok(ShapeModel::Ellipsoid));
+    assert_eq!("surfaces=1,-2/unprioritized/dsk".parse(), Ok(dsk.clone()));
+    for invalid in [
+        "",
+        "DSK",
+        "DSK/UNPRIORITIZED/DSK",
+        "DSK/UNPRIORITIZED/SURFACES = LOW",
+    ] {
+        assert_eq!(
+            invalid.parse::<ShapeModel>(),
+            Err(Error::InvalidMethod(invalid.to_string()))
+        );
+    }
+
+    // The strings left for CSPICE are sent unchanged.
+    let named = "DSK/UNPRIORITIZED/SURFACES = \"LOW RES\"";
+    assert_eq!(ShapeModel::from(named), ShapeModel::Raw(named.to_string()));
+    assert_eq!(ShapeModel::from(named).to_string(), named);
+    assert!(ShapeModel::from(named).is_dsk());
+    assert!(!ShapeModel::from("Ellipsoid: legacy").is_dsk());
+
+    let near = SubPointMethod::NearPoint(ShapeModel::Ellipsoid);
+    assert_eq!(near.to_string(), "NEAR POINT/ELLIPSOID");
+    assert_eq!(
+        SubPointMethod::NearPoint(dsk.clone()).to_string(),
+        "NADIR/DSK/UNPRIORITIZED/SURFACES = 1, -2"
+    );
+    assert_eq!(
+        SubPointMethod::Intercept(ShapeModel::dsk(&[])).to_string(),
+        "INTERCEPT/DSK/UNPRIORITIZED"
+    );
+    for method in [
+        near.clone(),
+        SubPointMethod::NearPoint(dsk.clone()),
+        SubPointMethod::Intercept(ShapeModel::Ellipsoid),
+        SubPointMethod::Intercept(dsk.clone()),
+    ] {
+        assert_eq!(method.to_string().parse(), Ok(method));
+    }
+    assert_eq!(SubPointMethod::from("Near Point"), near);
+    assert_eq!(
+        SubPointMethod::from("nadir/dsk/unprioritized").shape(),
+        Some(&ShapeModel::dsk(&[]))
+    );
+    for invalid in [
+        "NEAR POINT/DSK/UNPRIORITIZED",
+        "NADIR/ELLIPSOID",
+        "ELLIPSOID",
+    ] {
+        assert!(invalid.parse::<SubPointMethod>().is_err());
+    }
+    for legacy in ["Near point: ellipsoid", "Intercept: ellipsoid"] {
+        let method = SubPointMethod::from(legacy);
+        assert_eq!(method, SubPointMethod::Raw(legacy.to_string()));
+        assert_eq!(method.to_string(), legacy);
+        assert_eq!(method.shape(), None);
+    }
+    assert_eq!(
+        SubPointMethod::NearPoint(ShapeModel::from(named)).to_string(),
+        format!("NADIR/{}", named)
+    );
+}
+
+#[test]
+fn mesh() {
+    use spice::{MeshFormat, PlateModel};
+
+    // Tetrahedron whose plates face outwards.
+    let model = PlateModel::new(
+        vec![
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.5],
+        ],
+        vec![[1, 3, 2], [1, 2, 4], [1, 4, 3], [2, 3, 4]],
+    );
+    let corners = |model: &PlateModel| {
+        model
+            .plates
+            .iter()
+            .map(|plate| plate.map(|i| model.vertices[i as usize - 1]))
+            .collect::<Vec<_>>()
+    };
+    let normals = model.normals();
+    assert_eq!(normals[0], [0.0, 0.0, -1.0]);
+    assert_eq!(normals[1], [0.0, -1.0, 0.0]);
+
+    for with_normals in [None, Some(normals.as_slice())] {
+        let mut obj = vec![];
+        model.write_obj(&mut obj, with_normals).unwrap();
+        assert_eq!(PlateModel::read_obj(&obj).unwrap(), model);
+
+        for binary in [false, true] {
+            let mut ply = vec![];
+            model.write_ply(&mut ply, binary, with_normals).unwrap();
+            assert_eq!(PlateModel::read_ply(&ply).unwrap(), model);
+
+            // STL files do not index their vertices, which are numbered again when merged.
+            let mut stl = vec![];
+            model.write_stl(&mut stl, binary, with_normals).unwrap();
+            let read = PlateModel::read_stl(&stl).unwrap();
+            assert_eq!(read.vertices.len(), model.vertices.len());
+            assert_eq!(corners(&read), corners(&model));
+        }
+    }
+
+    // Polygons are split in plates, and negative indices are relative to the last vertex.
+    let obj = "# square\nv 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nvt 0 0\nf -4/1 -3/1 -2/1 -1/1\n";
+    assert_eq!(
+        PlateModel::read_obj(obj.as_bytes()).unwrap().plates,
+        vec![[1, 2, 3], [1, 3, 4]]
+    );
+    assert!(PlateModel::read_obj(b"v 0 0 0\nf 1 2 3\n").is_err());
+
+    let ply = "ply\r\nformat ascii 1.0\r\ncomment made by hand\r\nelement vertex 3\r\n\
+               property float x\r\nproperty float y\r\nproperty float z\r\nproperty uchar red\r\n\
+               element face 1\r\nproperty list uchar uint vertex_index\r\nend_header\r\n\
+               0 0 0 255\r\n1 0 0 0\r\n0 1 0 0\r\n3 0 1 2\r\n";
+    assert_eq!(
+        PlateModel::read_ply(ply.as_bytes()).unwrap(),
+        PlateModel::new(
+            vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            vec![[1, 2, 3]]
+        )
+    );
+
+    let path = std::env::temp_dir().join("rust-spice-mesh.ply");
+    model
+        .save(&path, MeshFormat::PlyBinary, Some(&normals))
+        .unwrap();
+    assert_eq!(PlateModel::load(&path).unwrap(), model);
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        PlateModel::load(&path),
+        Err(spice::Error::InvalidMesh(_))
+    ));
+
+    // Invalid models are rejected before anything is written.
+    assert!(model.validate().is_ok());
+    let short = Some(&normals[..2]);
+    assert!(matches!(
+        model.write_obj(&mut vec![], short),
+        Err(spice::Error::InvalidMesh(_))
+    ));
+    assert!(model.write_ply(&mut vec![], false, short).is_err());
+    assert!(model.write_stl(&mut vec![], true, short).is_err());
+    for plate in [[0, 1, 2], [1, 2, 5]] {
+        let invalid = PlateModel::new(model.vertices.clone(), vec![plate]);
+        assert!(matches!(
+            invalid.validate(),
+            Err(spice::Error::InvalidMesh(_))
+        ));
+        assert!(invalid.save(&path, MeshFormat::StlAscii, None).is_err());
+        assert!(!path.exists());
+    }
+}
+
+#[test]
+#[serial]
+fn mesh_dsk() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let handle = spice::dasopr("/Users/gregoireh/data/spice-kernels/hera/kernels/dsk/g_08438mm_lgt_obj_didb_0000n00000_v002.bds");
+    let (dladsc, _) = spice::dlabfs(handle);
+    let model = spice::PlateModel::from_dsk(handle, dladsc);
+    let normals = model.dsk_normals(handle, dladsc);
+    spice::dascls(handle);
+
+    assert_eq!(model.plates.len(), 3072);
+    assert_eq!(normals.len(), model.plates.len());
+    for (normal, expected) in multizip((normals.iter(), model.normals().iter())) {
+        for (component, expected) in multizip((normal.iter(), expected.iter())) {
+            assert_relative_eq!(component, expected, epsilon = 1e-12);
+        }
+    }
+
+    let path = std::env::temp_dir().join("rust-spice-mesh.obj");
+    model
+        .save(&path, spice::MeshFormat::Obj, Some(&normals))
+        .unwrap();
+    assert_eq!(spice::PlateModel::load(&path).unwrap(), model);
+    std::fs::remove_file(&path).unwrap();
+
+    spice::kclear();
+}
+
+#[test]
+fn limb_cuts() {
+    use spice::{CorrectionLocus, CurveType, Cut, Cuts, Shadow};
+
+    assert_eq!(CurveType::default().as_str(), "TANGENT");
+    assert_eq!(CurveType::Guided.as_str(), "GUIDED");
+    assert_eq!(Shadow::default().as_str(), "UMBRAL");
+    assert_eq!(Shadow::Penumbral.as_str(), "PENUMBRAL");
+    assert_eq!(CorrectionLocus::Center.as_limb_str(), "CENTER");
+    assert_eq!(CorrectionLocus::Ellipsoid.as_limb_str(), "ELLIPSOID LIMB");
+    assert_eq!(
+        CorrectionLocus::Ellipsoid.as_terminator_str(),
+        "ELLIPSOID TERMINATOR"
+    );
+
+    let cuts = Cuts::new([0.0, 0.0, 1.0], 4);
+    assert_relative_eq!(cuts.rolstp, std::f64::consts::FRAC_PI_2, epsilon = 1e-15);
+    assert_eq!(cuts.maxn, 400);
+
+    let points = [[1.0, 0.0, 0.0], [2.0, 0.0, 0.0], [3.0, 0.0, 0.0]];
+    let split = Cut::split(&[1, 0, 2], &points, &[1.0, 2.0, 3.0], &points);
+    assert_eq!(split.len(), 3);
+    assert_eq!(split[0].points, vec![[1.0, 0.0, 0.0]]);
+    assert!(split[1].points.is_empty());
+    assert_eq!(split[2].epochs, vec![2.0, 3.0]);
+}
+
+#[test]
+#[serial]
+fn limbpt_termpt() {
+    use spice::{CorrectionLocus, CurveType, Cuts, Shadow};
+
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+    let cuts = Cuts::new([0.0, 0.0, 1.0], 12);
+
+    for shape in ["ELLIPSOID", "DSK/UNPRIORITIZED"] {
+        let limb = spice::limbpt(
+            CurveType::Tangent,
+            shape,
+            "DIMORPHOS",
+            et,
+            "DIMORPHOS_FIXED",
+            "CN+S",
+            CorrectionLocus::Center,
+            "HERA",
+            &cuts,
+        );
+        assert_eq!(limb.len(), 12);
+        for cut in &limb {
+            assert!(!cut.points.is_empty());
+            assert_eq!(cut.points.len(), cut.epochs.len());
+            assert_eq!(cut.points.len(), cut.tangents.len());
+        }
+
+        let terminator = spice::termpt(
+            Shadow::Umbral,
+            CurveType::Tangent,
+            shape,
+            "SUN",
+            "DIMORPHOS",
+            et,
+            "DIMORPHOS_FIXED",
+            "CN+S",
+            CorrectionLocus::Center,
+            "HERA",
+            &cuts,
+        );
+        assert_eq!(terminator.len(), 12);
+        assert!(terminator.iter().all(|cut| !cut.points.is_empty()));
+    }
+
+    // On the ellipsoid, every cut has a single limb point, tangent to its line of sight.
+    let limb = spice::limbpt(
+        CurveType::Guided,
+        "ELLIPSOID",
+        "DIMORPHOS",
+        et,
+        "DIMORPHOS_FIXED",
+        "NONE",
+        CorrectionLocus::Ellipsoid,
+        "HERA",
+        &cuts,
+    );
+    assert!(limb.iter().all(|cut| cut.points.len() == 1));
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
+#[test]
+fn illumination() {
+    use spice::{Illumination, SubPoint};
+
+    // The flags are kept as given, whatever the angles.
+    let illumination = Illumination::from((1.0, [1.0, 2.0, 3.0], 0.5, 0.2, 0.3, false, true));
+    assert_eq!(illumination.trgepc, 1.0);
+    assert_eq!(illumination.srfvec, [1.0, 2.0, 3.0]);
+    assert_eq!(
+        (
+            illumination.phase,
+            illumination.incidence,
+            illumination.emission
+        ),
+        (0.5, 0.2, 0.3)
+    );
+    assert!(!illumination.visible);
+    assert!(illumination.lit);
+
+    let subpoint = SubPoint::from(([1.0, 0.0, 0.0], 2.0, [0.0, 1.0, 0.0]));
+    assert_eq!(subpoint.spoint, [1.0, 0.0, 0.0]);
+    assert_eq!(subpoint.trgepc, 2.0);
+    assert_eq!(subpoint.srfvec, [0.0, 1.0, 0.0]);
+}
+
+#[test]
+#[serial]
+fn illumination_family() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+
+    let subsolar = spice::subslr(
+        spice::SubPointMethod::NearPoint(spice::ShapeModel::Ellipsoid),
+        "DIMORPHOS",
+        et,
+        "DIMORPHOS_FIXED",
+        "CN+S",
+        "HERA",
+    );
+    let ilumin = spice::ilumin(
+        "ELLIPSOID",
+        "DIMORPHOS",
+        et,
+        "DIMORPHOS_FIXED",
+        "CN+S",
+        "HERA",
+        subsolar.spoint,
+    );
+    assert_relative_eq!(ilumin.incidence, 0.0, epsilon = 1e-6);
+    assert!(ilumin.lit);
+    assert_relative_eq!(ilumin.trgepc, subsolar.trgepc, epsilon = 1e-6);
+    let (trgepc, srfvec, phase, incidence, emission) = spice::raw::ilumin(
+        "ELLIPSOID",
+        "DIMORPHOS",
+        et,
+        "DIMORPHOS_FIXED",
+        "CN+S",
+        "HERA",
+        subsolar.spoint,
+    );
+    assert_eq!(
+        (ilumin.trgepc, ilumin.srfvec, ilumin.phase),
+        (trgepc, srfvec, phase)
+    );
+    assert_eq!((ilumin.incidence, ilumin.emission), (incidence, emission));
+
+    let illumg = spice::illumg(
+        "ELLIPSOID",
+        "DIMORPHOS",
+        "SUN",
+        et,
+        "DIMORPHOS_FIXED",
+        "CN+S",
+        "HERA",
+        subsolar.spoint,
+    );
+    assert_eq!(illumg, ilumin);
+
+    let illumf: spice::Illumination = spice::illumf(
+        "ELLIPSOID",
+        "DIMORPHOS",
+        "SUN",
+        et,
+        "DIMORPHOS_FIXED",
+        "CN+S",
+        "HERA",
+        subsolar.spoint,
+    )
+    .into();
+    assert_eq!(illumf, ilumin);
+
+    // The phase angle of the target center is close to the one of its sub-solar point.
+    let phase = spice::phaseq(et, "DIMORPHOS", "SUN", "HERA", "LT+S");
+    assert!((0.0..=std::f64::consts::PI).contains(&phase));
+    assert_relative_eq!(phase, ilumin.phase, epsilon = 0.1);
+
+    spice::unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+}
+
+#[test]
+#[serial]
+fn planes() {
+    let plane = spice::nvc2pl([0.0, 0.0, 2.0], 3.0);
+    assert_eq!(plane.normal, [0.0, 0.0, 1.0]);
+    assert_relative_eq!(plane.constant, 1.5, epsilon = f64::EPSILON);
+    assert_eq!(spice::pl2nvc(plane), (plane.normal, plane.constant));
+    assert_eq!(spice::nvp2pl([0.0, 0.0, 1.0], [4.0, 5.0, 1.5]), plane);
+    assert_eq!(
+        spice::psv2pl([4.0, 5.0, 1.5], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]),
+        plane
+    );
+
+    // The ray from above crosses the plane once, the horizontal ray never does.
+    let (nxpts, xpt) = spice::inrypl([1.0, 2.0, 10.0], [0.0, 0.0, -1.0], plane);
+    assert_eq!(nxpts, 1);
+    assert_eq!(xpt, [1.0, 2.0, 1.5]);
+    assert_eq!(spice::inrypl([1.0, 2.0, 10.0], [1.0, 0.0, 0.0], plane).0, 0);
+
+    // Sphere of radius 2 cut at height 1.5.
+    let (ellipse, found) = spice::inedpl(2.0, 2.0, 2.0, plane);
+    assert!(found);
+    assert_eq!(ellipse.center, [0.0, 0.0, 1.5]);
+    assert_relative_eq!(spice::vdot(ellipse.semi_major, ellipse.semi_minor), 0.0);
+    assert_relative_eq!(
+        spice::vdot(ellipse.semi_major, ellipse.semi_major),
+        4.0 - 1.5 * 1.5,
+        epsilon = 1e-14
+    );
+    assert!(!spice::inedpl(1.0, 1.0, 1.0, plane).1);
+
+    // Limb of a sphere of radius 1 seen from a distance of 2 along Z.
+    let limb = spice::edlimb(1.0, 1.0, 1.0, [0.0, 0.0, 2.0]);
+    assert_relative_eq!(limb.center[2], 0.5, epsilon = f64::EPSILON);
+    assert_relative_eq!(
+        spice::vdot(limb.semi_major, limb.semi_major),
+        0.75,
+        epsilon = 1e-14
+    );
+
+    let (pnear, dist) = spice::npelpt([0.0, 0.0, 10.0], limb);
+    assert_relative_eq!(pnear[2], 0.5, epsilon = 1e-14);
+    assert_relative_eq!(dist, (0.75f64 + 9.5 * 9.5).sqrt(), epsilon = 1e-12);
+
+    let (npoint, alt) = spice::nearpt([0.0, 5.0, 0.0], 3.0, 2.0, 1.0);
+    assert_relative_eq!(npoint[1], 2.0, epsilon = 1e-14);
+    assert_relative_eq!(alt, 3.0, epsilon = 1e-14);
+    assert_eq!(
+        spice::surfnm(3.0, 2.0, 1.0, [0.0, 0.0, 1.0]),
+        [0.0, 0.0, 1.0]
+    );
+
+    let (pnear, dist) = spice::npedln(1.0, 1.0, 1.0, [0.0, 0.0, 3.0], [1.0, 0.0, 0.0]);
+    assert_relative_eq!(pnear[2], 1.0, epsilon = 1e-14);
+    assert_relative_eq!(dist, 2.0, epsilon = 1e-14);
+
+    let (smajor, sminor) = spice::saelgv([2.0, 0.0, 0.0], [0.0, 1.0, 0.0]);
+    assert_relative_eq!(smajor[0].abs(), 2.0, epsilon = 1e-14);
+    assert_relative_eq!(sminor[1].abs(), 1.0, epsilon = 1e-14);
+}
+
+#[test]
+#[serial]
+fn surface() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let handle = spice::dasopr("/Users/gregoireh/data/spice-kernels/hera/kernels/dsk/g_08438mm_lgt_obj_didb_0000n00000_v002.bds");
+    let (dladsc, _) = spice::dlabfs(handle);
+    let dskdsc = spice::dskgd(handle, dladsc);
+    spice::dascls(handle);
+
+    let dimorphos = spice::Body::from_name("DIMORPHOS").unwrap();
+    assert_eq!(dskdsc.center, dimorphos.id());
+
+    let surface = spice::Surface::new(dskdsc.surfce, dimorphos);
+    assert_eq!(
+        spice::Surface::from_name(&dskdsc.surfce.to_string(), dimorphos),
+        Some(surface)
+    );
+    assert_eq!(
+        spice::srfs2c(&dskdsc.surfce.to_string(), "DIMORPHOS"),
+        (dskdsc.surfce, true)
+    );
+
+    let (name, isname) = spice::srfc2s(surface.id(), dimorphos.id());
+    assert_eq!(
+        spice::srfcss(surface.id(), "DIMORPHOS"),
+        (name.clone(), isname)
+    );
+    match surface.name() {
+        Some(surface_name) => {
+            assert!(isname);
+            assert_eq!(surface_name, name);
+            assert_eq!(spice::Surface::from_name(&name, dimorphos), Some(surface));
+            assert_eq!(spice::srfscc(&name, dimorphos.id()), (surface.id(), true));
+        }
+        None => assert_eq!(name, surface.id().to_string()),
+    }
+
+    assert_eq!(spice::Surface::dsk_method(&[]), "DSK/UNPRIORITIZED");
+    let method = spice::Surface::dsk_method(&[surface]);
+    assert_eq!(
+        method,
+        format!("DSK/UNPRIORITIZED/SURFACES = {}", surface.id())
+    );
+
+    assert_eq!(spice::ShapeModel::dsk(&[surface]).to_string(), method);
+
+    // The only surface of Dimorphos gives the same surface points as all of them.
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+    let lonlat = [[0.0, 0.0], [1.0, 0.5]];
+    assert_eq!(
+        spice::latsrf(&method, "DIMORPHOS", et, "DIMORPHOS_FIXED", &lonlat),
+        spice::latsrf(
+            spice::ShapeModel::dsk(&[]),
+            "DIMORPHOS",
+            et,
+            "DIMORPHOS_FIXED",
+            &lonlat
+        )
+    );
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn vdot() {
+    assert_eq!(spice::vdot([1.0, 2.0, 3.0], [1.0, 2.0, 3.0]), 14.0)
+}
+
+#[test]
+#[serial]
+fn vcrss() {
+    // Examples from https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/vcrss_c.html
+    assert_eq!(
+        spice::vcrss([0.0, 1.0, 0.0], [1.0, 0.0, 0.0]),
+        [0.0, 0.0, -1.0]
+    );
+    assert_eq!(
+        spice::vcrss([5.0, 5.0, 5.0], [-1.0, -1.0, -1.0]),
+        [0.0, 0.0, 0.0]
+    );
+}
+
+#[test]
+#[serial]
+fn vsep() {
+    let ang_1 = spice::vsep([1., 0., 0.], [1., 0., 0.]);
+    let ang_2 = spice::vsep([1., 0., 0.], [0., 1., 0.]);
+
+    assert_relative_eq!(ang_1, 0.0, epsilon = f64::EPSILON);
+    assert_relative_eq!(ang_2, std::f64::consts::TAU / 4., epsilon = f64::EPSILON);
+}
+
+#[test]
+#[serial]
+fn kdata() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+    let index_dsk = 1;
+
+    let count = spice::ktotal("dsk");
+    assert_eq!(count, 2);
+
+    let (file, filtyp, source, handle, found) = spice::kdata(index_dsk, "dsk");
+    assert_eq!(
+        file,
+        "/Users/gregoireh/data/spice-kernels/hera/kernels/dsk/g_08438mm_lgt_obj_didb_0000n00000_v002.bds"
+    );
+    assert_eq!(filtyp, "DSK");
+    assert_eq!(source, "/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+    assert!(handle.is_positive());
+    assert_eq!(found, true);
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn cell() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let (file, _, _, _, found) = spice::kdata(1, "dsk");
+    assert!(found);
+
+    let cell = spice::dskobj(&file);
+
+    assert_eq!(cell.card, 1);
+    assert_eq!(cell.get_data_int(0), -658031);
+
+    assert_eq!(spice::bodc2n(cell.get_data_int(0)).0, "DIMORPHOS");
 
     spice::kclear();
 }
@@ -436,3 +2461,31 @@ fn bodfnd() {
 
     spice::kclear();
 }
+
+#[test]
+#[serial]
+fn body() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let dimorphos = spice::Body::from_name("DIMORPHOS").unwrap();
+    assert_eq!(dimorphos.id(), -658031);
+    assert_eq!(spice::Body::from_id(-658031), dimorphos);
+    assert_eq!(dimorphos.name().unwrap(), "DIMORPHOS");
+
+    let radii = dimorphos.radii().unwrap();
+    assert_eq!(radii[..], spice::bodvrd("DIMORPHOS", "RADII", 3)[..]);
+    assert_eq!(spice::dtpool("BODY-658031_RADII"), (3, 'N', true));
+    assert_eq!(dimorphos.values("RADII").unwrap(), radii);
+    assert!(spice::Body::from_name("NOT A BODY").is_none());
+
+    let nameless = spice::Body::from_id(-999998);
+    assert!(nameless.name().is_none());
+    assert_eq!(nameless.to_string(), "-999998");
+    assert!(nameless.values("RADII").is_none());
+
+    let custom = spice::Body::define("RUST-SPICE TEST BODY", -999999);
+    assert_eq!(spice::Body::from_name("RUST-SPICE TEST BODY"), Some(custom));
+    assert!(custom.radii().is_none());
+
+    spice::kclear();
+}